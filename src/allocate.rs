@@ -0,0 +1,94 @@
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign, RoundingMode};
+
+// (a * b) / c and its remainder, the product is kept in 256 bits
+fn mul_div(a: u128, b: u128, c: u128) -> (u128, u128) {
+    const LOW: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & LOW);
+    let (b_high, b_low) = (b >> 64, b & LOW);
+    let low = a_low * b_low;
+    let middle_0 = a_high * b_low;
+    let middle_1 = a_low * b_high;
+    let (middle, middle_carry) = middle_0.overflowing_add(middle_1);
+    let (product_low, low_carry) = low.overflowing_add(middle << 64);
+    let product_high = a_high * b_high + (middle >> 64) + ((middle_carry as u128) << 64) + low_carry as u128;
+    let mut quotient: u128 = 0;
+    let mut remainder: u128 = 0;
+    for bit in (0..256).rev() {
+        let next = if bit >= 128 { (product_high >> (bit - 128)) & 1 } else { (product_low >> bit) & 1 };
+        let carry = remainder >> 127 == 1;
+        remainder = remainder << 1 | next;
+        if carry || remainder >= c {
+            remainder = remainder.wrapping_sub(c);
+            if bit < 128 {
+                quotient |= 1 << bit;
+            }
+        }
+    }
+    (quotient, remainder)
+}
+
+impl Decimal {
+    /// Splits the value by `weights` into parts at `scale` that sum exactly to the value.
+    /// Leftover minor units go to the parts with the largest remainders, ties to the first parts.
+    pub fn allocate(&self, weights: &[Decimal], scale: DecimalScale) -> Result<Vec<Decimal>, DecimalError> {
+        let amount = self.rescale(scale, RoundingMode::Unnecessary)?.coefficient();
+        let weights_scale = weights.iter().map(|weight| weight.scale as u32).max().unwrap_or(0);
+        let mut units: Vec<u128> = Vec::with_capacity(weights.len());
+        for weight in weights {
+            if weight.sign == DecimalSign::Negative && weight.coefficient() != 0 {
+                return Err(DecimalError); // Negative weight
+            }
+            match 10u128.checked_pow(weights_scale - weight.scale as u32).and_then(|factor| weight.coefficient().checked_mul(factor)) {
+                Some(value) => units.push(value),
+                None => return Err(DecimalError),
+            }
+        }
+        let total = match units.iter().try_fold(0u128, |total, value| total.checked_add(*value)) {
+            Some(value) if value > 0 => value,
+            _ => return Err(DecimalError), // No weights, zero weights or overflow
+        };
+        let mut parts: Vec<(u128, u128)> = units.iter().map(|value| mul_div(amount, *value, total)).collect();
+        let leftover = amount - parts.iter().map(|part| part.0).sum::<u128>();
+        let mut order: Vec<usize> = (0..parts.len()).collect();
+        order.sort_by(|lhs, rhs| parts[*rhs].1.cmp(&parts[*lhs].1));
+        for index in order.into_iter().take(leftover as usize) {
+            parts[index].0 += 1;
+        }
+        parts.iter().map(|part| Decimal::from_coefficient(self.sign, scale, part.0)).collect()
+    }
+    /// Splits the value into `count` parts at `scale`, leftover minor units go to the first parts.
+    pub fn split_evenly(&self, count: usize, scale: DecimalScale) -> Result<Vec<Decimal>, DecimalError> {
+        if count == 0 {
+            return Err(DecimalError);
+        }
+        let amount = self.rescale(scale, RoundingMode::Unnecessary)?.coefficient();
+        let (share, leftover) = (amount / count as u128, (amount % count as u128) as usize);
+        (0..count)
+            .map(|index| Decimal::from_coefficient(self.sign, scale, if index < leftover { share + 1 } else { share }))
+            .collect()
+    }
+}
+
+#[test]
+fn decimal_allocate_works() {
+    let to_strings = |parts: Vec<Decimal>| parts.iter().map(|part| part.to_string()).collect::<Vec<String>>();
+    let amount = Decimal::parse("100.00").unwrap();
+    assert_eq!(to_strings(amount.split_evenly(3, DecimalScale::Scale02).unwrap()), ["33.34", "33.33", "33.33"]);
+    let weights = [Decimal::parse("1").unwrap(), Decimal::parse("1").unwrap(), Decimal::parse("1").unwrap()];
+    assert_eq!(to_strings(amount.allocate(&weights, DecimalScale::Scale02).unwrap()), ["33.34", "33.33", "33.33"]);
+
+    let weights = [Decimal::parse("0.3").unwrap(), Decimal::parse("0.7").unwrap()];
+    assert_eq!(to_strings(Decimal::parse("0.05").unwrap().allocate(&weights, DecimalScale::Scale02).unwrap()), ["0.02", "0.03"]);
+    let weights = [Decimal::parse("50").unwrap(), Decimal::parse("30").unwrap(), Decimal::parse("20").unwrap()];
+    assert_eq!(to_strings(Decimal::parse("-10.01").unwrap().allocate(&weights, DecimalScale::Scale02).unwrap()), ["-5.01", "-3.00", "-2.00"]);
+
+    let large = Decimal::parse("99999999999999999999999999999999999.99").unwrap();
+    let weights = [Decimal::parse("99999999999999999999999999999999999999").unwrap(), Decimal::parse("1").unwrap()];
+    let parts = large.allocate(&weights, DecimalScale::Scale02).unwrap();
+    assert_eq!(parts[0].coefficient() + parts[1].coefficient(), large.coefficient());
+    assert_eq!((parts[0].coefficient(), parts[1].coefficient()), (large.coefficient(), 0));
+
+    assert!(amount.split_evenly(0, DecimalScale::Scale02).is_err());
+    assert!(amount.allocate(&[], DecimalScale::Scale02).is_err());
+    assert!(Decimal::parse("1.005").unwrap().split_evenly(2, DecimalScale::Scale02).is_err());
+}
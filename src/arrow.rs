@@ -0,0 +1,79 @@
+use arrow_array::{Array, Decimal128Array};
+
+use crate::{Decimal, DecimalError, DecimalSign, RoundingMode};
+
+impl Decimal {
+    /// Arrow `Decimal128Array` of the given precision and scale (the scale may be negative as in Arrow).
+    /// Values are rounded to the scale with `mode`, `RoundingMode::Unnecessary` rejects any that would change,
+    /// and values with more digits than the precision are rejected.
+    pub fn to_arrow_decimal128(values: &[Decimal], precision: u8, scale: i8, mode: RoundingMode) -> Result<Decimal128Array, DecimalError> {
+        if precision == 0 || precision > 38 || scale > 38 {
+            return Err(DecimalError); // Not a Decimal128 type
+        }
+        let mut unscaled = Vec::with_capacity(values.len());
+        for value in values {
+            let negative = value.sign == DecimalSign::Negative;
+            let coefficient = if scale as i32 >= value.scale as i32 {
+                match 10u128.checked_pow((scale as i32 - value.scale as i32) as u32).and_then(|factor| value.coefficient().checked_mul(factor)) {
+                    Some(coefficient) => coefficient,
+                    None => return Err(DecimalError), // Overflow
+                }
+            } else {
+                mode.round(value.coefficient(), (value.scale as i32 - scale as i32) as u32, negative)?
+            };
+            if coefficient >= 10u128.pow(precision as u32) {
+                return Err(DecimalError); // More digits than the precision
+            }
+            unscaled.push(if negative { -(coefficient as i128) } else { coefficient as i128 });
+        }
+        match Decimal128Array::from_iter_values(unscaled).with_precision_and_scale(precision, scale) {
+            Ok(array) => Ok(array),
+            Err(_) => Err(DecimalError),
+        }
+    }
+    /// Values of an Arrow `Decimal128Array`, nulls as `None`.
+    pub fn from_arrow_decimal128(array: &Decimal128Array) -> Result<Vec<Option<Decimal>>, DecimalError> {
+        let scale = array.scale();
+        let mut result = Vec::with_capacity(array.len());
+        for index in 0..array.len() {
+            if array.is_null(index) {
+                result.push(None);
+                continue;
+            }
+            let value = array.value(index);
+            let decimal = if scale >= 0 {
+                Decimal::from_i128_with_scale(value, scale as u8)?
+            } else {
+                match 10i128.checked_pow(scale.unsigned_abs() as u32).and_then(|factor| value.checked_mul(factor)) {
+                    Some(value) => Decimal::from_i128_with_scale(value, 0)?,
+                    None => return Err(DecimalError), // Overflow
+                }
+            };
+            result.push(Some(decimal));
+        }
+        Ok(result)
+    }
+}
+
+#[test]
+fn decimal_arrow_works() {
+    let values = [Decimal::parse("1.5").unwrap(), Decimal::parse("-2.25").unwrap(), Decimal::parse("1000").unwrap()];
+    let array = Decimal::to_arrow_decimal128(&values, 10, 2, RoundingMode::Unnecessary).unwrap();
+    assert_eq!((array.precision(), array.scale()), (10, 2));
+    assert_eq!(array.values().to_vec(), vec![150, -225, 100000]);
+    let decoded = Decimal::from_arrow_decimal128(&array).unwrap();
+    assert_eq!(decoded.iter().map(|value| value.unwrap().mantissa()).collect::<Vec<_>>(), vec![150, -225, 100000]);
+
+    let array = Decimal::to_arrow_decimal128(&values, 10, 1, RoundingMode::HalfEven).unwrap();
+    assert_eq!(array.values().to_vec(), vec![15, -22, 10000]);
+    assert!(Decimal::to_arrow_decimal128(&values, 10, 1, RoundingMode::Unnecessary).is_err());
+    assert!(Decimal::to_arrow_decimal128(&values, 5, 2, RoundingMode::HalfEven).is_err()); // 1000.00 needs 6 digits
+    let array = Decimal::to_arrow_decimal128(&values, 3, -2, RoundingMode::HalfUp).unwrap();
+    assert_eq!(array.values().to_vec(), vec![0, 0, 10]);
+    assert_eq!(Decimal::from_arrow_decimal128(&array).unwrap()[2].unwrap().mantissa(), 1000);
+
+    let array = Decimal128Array::from(vec![Some(12345), None]).with_precision_and_scale(38, 3).unwrap();
+    let decoded = Decimal::from_arrow_decimal128(&array).unwrap();
+    assert_eq!((decoded[0].unwrap().mantissa(), decoded[0].unwrap().scale()), (12345, 3));
+    assert_eq!(decoded[1], None);
+}
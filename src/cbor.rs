@@ -0,0 +1,206 @@
+use std::convert::TryFrom;
+
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign};
+
+const CBOR_UNSIGNED: u8 = 0;
+const CBOR_NEGATIVE: u8 = 1;
+const CBOR_BYTES: u8 = 2;
+const CBOR_ARRAY: u8 = 4;
+const CBOR_TAG: u8 = 6;
+const CBOR_TAG_POSITIVE_BIGNUM: u64 = 2;
+const CBOR_TAG_NEGATIVE_BIGNUM: u64 = 3;
+const CBOR_TAG_DECIMAL_FRACTION: u64 = 4;
+
+// Major type and argument in the shortest form
+fn write_head(result: &mut Vec<u8>, major: u8, argument: u64) {
+    let major = major << 5;
+    match argument {
+        0..=23 => result.push(major | argument as u8),
+        24..=0xFF => result.extend_from_slice(&[major | 24, argument as u8]),
+        0x100..=0xFFFF => {
+            result.push(major | 25);
+            result.extend_from_slice(&(argument as u16).to_be_bytes());
+        },
+        0x1_0000..=0xFFFF_FFFF => {
+            result.push(major | 26);
+            result.extend_from_slice(&(argument as u32).to_be_bytes());
+        },
+        _ => {
+            result.push(major | 27);
+            result.extend_from_slice(&argument.to_be_bytes());
+        },
+    }
+}
+
+// Major type and argument at the start of `bytes`, advancing past them
+fn read_head(bytes: &mut &[u8]) -> Result<(u8, u64), DecimalError> {
+    let (&first, rest) = match bytes.split_first() {
+        Some(value) => value,
+        None => return Err(DecimalError), // Truncated
+    };
+    let length = match first & 0x1F {
+        0..=23 => 0,
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => return Err(DecimalError), // Indefinite length or reserved
+    };
+    if rest.len() < length {
+        return Err(DecimalError); // Truncated
+    }
+    let argument = if length == 0 {
+        (first & 0x1F) as u64
+    } else {
+        let mut buffer = [0u8; 8];
+        buffer[8 - length..].copy_from_slice(&rest[..length]);
+        u64::from_be_bytes(buffer)
+    };
+    *bytes = &rest[length..];
+    Ok((first >> 5, argument))
+}
+
+// Signed integer or bignum as a sign and magnitude
+fn read_integer(bytes: &mut &[u8]) -> Result<(bool, u128), DecimalError> {
+    match read_head(bytes)? {
+        (CBOR_UNSIGNED, value) => Ok((false, value as u128)),
+        (CBOR_NEGATIVE, value) => Ok((true, value as u128 + 1)),
+        (CBOR_TAG, tag) if tag == CBOR_TAG_POSITIVE_BIGNUM || tag == CBOR_TAG_NEGATIVE_BIGNUM => {
+            let length = match read_head(bytes)? {
+                (CBOR_BYTES, length) if length as usize <= bytes.len() => length as usize,
+                _ => return Err(DecimalError), // Bignum content must be a byte string
+            };
+            let (content, rest) = bytes.split_at(length);
+            *bytes = rest;
+            let significant = content.iter().position(|byte| *byte != 0).map_or(&content[..0], |index| &content[index..]);
+            if significant.len() > 16 {
+                return Err(DecimalError); // Overflow
+            }
+            let mut buffer = [0u8; 16];
+            buffer[16 - significant.len()..].copy_from_slice(significant);
+            let value = u128::from_be_bytes(buffer);
+            if tag == CBOR_TAG_POSITIVE_BIGNUM {
+                Ok((false, value))
+            } else {
+                match value.checked_add(1) {
+                    Some(value) => Ok((true, value)),
+                    None => Err(DecimalError), // Overflow
+                }
+            }
+        },
+        _ => Err(DecimalError), // Not an integer
+    }
+}
+
+impl Decimal {
+    /// RFC 8949 decimal fraction: tag 4 around `[exponent, mantissa]`, the mantissa as a bignum
+    /// (tag 2 or 3) when it does not fit in 64 bits.
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut result = Vec::new();
+        write_head(&mut result, CBOR_TAG, CBOR_TAG_DECIMAL_FRACTION);
+        write_head(&mut result, CBOR_ARRAY, 2);
+        match self.scale as u64 {
+            0 => write_head(&mut result, CBOR_UNSIGNED, 0),
+            scale => write_head(&mut result, CBOR_NEGATIVE, scale - 1), // Exponent -scale
+        }
+        let negative = self.sign == DecimalSign::Negative && self.coefficient() != 0;
+        // Negative integers are stored as -1 - n
+        let argument = if negative { self.coefficient() - 1 } else { self.coefficient() };
+        match (u64::try_from(argument), negative) {
+            (Ok(argument), false) => write_head(&mut result, CBOR_UNSIGNED, argument),
+            (Ok(argument), true) => write_head(&mut result, CBOR_NEGATIVE, argument),
+            (Err(_), _) => {
+                write_head(&mut result, CBOR_TAG, if negative { CBOR_TAG_NEGATIVE_BIGNUM } else { CBOR_TAG_POSITIVE_BIGNUM });
+                let bytes = argument.to_be_bytes();
+                let significant = &bytes[bytes.iter().position(|byte| *byte != 0).unwrap()..];
+                write_head(&mut result, CBOR_BYTES, significant.len() as u64);
+                result.extend_from_slice(significant);
+            },
+        }
+        result
+    }
+    /// Reads a tag 4 decimal fraction, or a plain integer or bignum. Values that do not fit exactly are errors.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Decimal, DecimalError> {
+        let mut rest = bytes;
+        let mut peek = rest;
+        let decimal = match read_head(&mut peek)? {
+            (CBOR_TAG, CBOR_TAG_DECIMAL_FRACTION) => {
+                rest = peek;
+                if read_head(&mut rest)? != (CBOR_ARRAY, 2) {
+                    return Err(DecimalError); // Content must be a two element array
+                }
+                let exponent = match read_head(&mut rest)? {
+                    (CBOR_UNSIGNED, value) => i64::try_from(value).map_err(|_| DecimalError)?,
+                    (CBOR_NEGATIVE, value) => -1 - i64::try_from(value).map_err(|_| DecimalError)?,
+                    _ => return Err(DecimalError), // Exponent must be a small integer
+                };
+                let (negative, mantissa) = read_integer(&mut rest)?;
+                Decimal::from_cbor_exponent(negative, mantissa, exponent)?
+            },
+            _ => {
+                let (negative, mantissa) = read_integer(&mut rest)?;
+                Decimal::from_cbor_exponent(negative, mantissa, 0)?
+            },
+        };
+        if !rest.is_empty() {
+            return Err(DecimalError); // Trailing bytes
+        }
+        Ok(decimal)
+    }
+    // mantissa * 10^exponent
+    fn from_cbor_exponent(negative: bool, mantissa: u128, exponent: i64) -> Result<Decimal, DecimalError> {
+        let (mut mantissa, mut exponent) = (mantissa, exponent);
+        // Trailing zeros of the mantissa can bring the exponent into range, 4([-40, 100]) is 1E-38
+        while exponent < -38 && mantissa != 0 && mantissa.is_multiple_of(10) {
+            mantissa /= 10;
+            exponent += 1;
+        }
+        let sign = if negative { DecimalSign::Negative } else { DecimalSign::Positive };
+        if exponent >= 0 {
+            match u32::try_from(exponent).ok().and_then(|exponent| 10u128.checked_pow(exponent)).and_then(|factor| mantissa.checked_mul(factor)) {
+                Some(value) => Decimal::from_coefficient(sign, DecimalScale::Scale00, value),
+                None if mantissa == 0 => Decimal::from_coefficient(sign, DecimalScale::Scale00, 0),
+                None => Err(DecimalError), // Overflow
+            }
+        } else if exponent < -38 && mantissa == 0 {
+            Decimal::from_coefficient(sign, DecimalScale::Scale00, 0)
+        } else if exponent < -38 {
+            Err(DecimalError) // Below the smallest scale
+        } else {
+            Decimal::from_coefficient(sign, DecimalScale::try_from_usize(exponent.unsigned_abs() as usize).unwrap(), mantissa)
+        }
+    }
+}
+
+#[test]
+fn decimal_cbor_works() {
+    // RFC 8949 section 3.4.4: 273.15 = 4([-2, 27315])
+    let decimal = Decimal::parse("273.15").unwrap();
+    assert_eq!(decimal.to_cbor(), vec![0xC4, 0x82, 0x21, 0x19, 0x6A, 0xB3]);
+    assert_eq!(Decimal::from_cbor(&[0xC4, 0x82, 0x21, 0x19, 0x6A, 0xB3]).unwrap(), decimal);
+    assert_eq!(Decimal::parse("-1").unwrap().to_cbor(), vec![0xC4, 0x82, 0x00, 0x20]);
+    assert_eq!(Decimal::parse("0").unwrap().to_cbor(), vec![0xC4, 0x82, 0x00, 0x00]);
+    assert_eq!(Decimal::from_cbor(&[0xC4, 0x82, 0x02, 0x20]).unwrap().mantissa(), -100); // -1E+2
+
+    // 10^20 does not fit in 64 bits: 2(h'056BC75E2D63100000')
+    let big = Decimal::from_i128_with_scale(100_000_000_000_000_000_000, 0).unwrap();
+    let bytes = big.to_cbor();
+    assert_eq!(bytes, vec![0xC4, 0x82, 0x00, 0xC2, 0x49, 0x05, 0x6B, 0xC7, 0x5E, 0x2D, 0x63, 0x10, 0x00, 0x00]);
+    assert_eq!(Decimal::from_cbor(&bytes).unwrap().mantissa(), 100_000_000_000_000_000_000);
+    let min = crate::DECIMAL_MIN;
+    assert_eq!(Decimal::from_cbor(&min.to_cbor()).unwrap().mantissa(), min.mantissa());
+    let small = Decimal::from_i128_with_scale(-(u64::MAX as i128) - 1, 38).unwrap(); // -2^64 fits the negative argument
+    assert_eq!(small.to_cbor()[..5], [0xC4, 0x82, 0x38, 0x25, 0x3B]);
+    assert_eq!(Decimal::from_cbor(&small.to_cbor()).unwrap(), small);
+
+    assert_eq!(Decimal::from_cbor(&[0x19, 0x6A, 0xB3]).unwrap().mantissa(), 27315); // Plain integer
+    assert!(Decimal::from_cbor(&[0xC4, 0x82, 0x38, 0x27, 0x19, 0x6A, 0xB3]).is_err()); // 27315E-40
+    let tiny = Decimal::from_cbor(&[0xC4, 0x82, 0x38, 0x27, 0x18, 0x64]).unwrap(); // 100E-40
+    assert_eq!((tiny.mantissa(), tiny.scale()), (1, 38));
+    assert_eq!(Decimal::from_cbor(&[0xC4, 0x82, 0x38, 0x63, 0x00]).unwrap().mantissa(), 0); // 0E-100
+    assert!(Decimal::from_cbor(&[0xC4, 0x82, 0x38, 0x27, 0x18, 0x65]).is_err()); // 101E-40
+    assert!(Decimal::from_cbor(&[0xC4, 0x82, 0x21]).is_err()); // Truncated
+    assert!(Decimal::from_cbor(&[0xC5, 0x82, 0x21, 0x01]).is_err()); // Bigfloat
+    assert!(Decimal::from_cbor(&[0xC4, 0x82, 0x18, 0x26, 0x01]).is_err()); // 10^38
+    assert!(Decimal::from_cbor(&[0x01, 0x01]).is_err()); // Trailing bytes
+}
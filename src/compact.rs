@@ -0,0 +1,122 @@
+use crate::{Decimal, DecimalError, DecimalLocale, DecimalSign, RoundingMode, DECIMAL_LOCALE_DE_DE, DECIMAL_LOCALE_EN_US, DECIMAL_LOCALE_RU_RU};
+
+/// Settings of `Decimal::to_compact_string`: thousand, million, billion and trillion suffixes,
+/// the text between number and suffix, significant digits and rounding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecimalCompactOptions<'a> {
+    pub significant_digits: u8,
+    pub rounding: RoundingMode,
+    pub locale: DecimalLocale,
+    pub suffixes: [&'a str; 4],
+    pub separator: &'a str,
+}
+
+pub const DECIMAL_COMPACT_EN: DecimalCompactOptions<'static> = DecimalCompactOptions {
+    significant_digits: 3,
+    rounding: RoundingMode::HalfUp,
+    locale: DECIMAL_LOCALE_EN_US,
+    suffixes: ["K", "M", "B", "T"],
+    separator: "",
+};
+
+pub const DECIMAL_COMPACT_RU: DecimalCompactOptions<'static> = DecimalCompactOptions {
+    significant_digits: 3,
+    rounding: RoundingMode::HalfUp,
+    locale: DECIMAL_LOCALE_RU_RU,
+    suffixes: ["тыс.", "млн", "млрд", "трлн"],
+    separator: "\u{00A0}",
+};
+
+pub const DECIMAL_COMPACT_DE: DecimalCompactOptions<'static> = DecimalCompactOptions {
+    significant_digits: 3,
+    rounding: RoundingMode::HalfUp,
+    locale: DECIMAL_LOCALE_DE_DE,
+    suffixes: ["Tsd.", "Mio.", "Mrd.", "Bio."],
+    separator: "\u{00A0}",
+};
+
+impl Decimal {
+    /// Short form for dashboards: 1234 -> 1.23K, 3450000 -> 3.45M, 7000000000 -> 7B.
+    pub fn to_compact_string(&self, options: &DecimalCompactOptions) -> Result<String, DecimalError> {
+        if options.significant_digits == 0 || options.significant_digits > 38 {
+            return Err(DecimalError);
+        }
+        let coefficient = self.coefficient();
+        if coefficient == 0 {
+            return Ok(String::from("0"));
+        }
+        let negative = self.sign == DecimalSign::Negative;
+        let digits = coefficient.ilog10() as i32 + 1;
+        let magnitude = digits - 1 - self.scale as i32; // Position of the most significant digit
+        let mut tier = (magnitude / 3).clamp(0, options.suffixes.len() as i32);
+        loop {
+            // The value divided by 1000^tier is coefficient * 10^-scale
+            let mut scale = self.scale as i32 + 3 * tier;
+            let mut rounded = coefficient;
+            if digits > options.significant_digits as i32 {
+                let dropped = (digits - options.significant_digits as i32) as u32;
+                rounded = options.rounding.round(coefficient, dropped, negative)?;
+                scale -= dropped as i32;
+            }
+            while scale > 0 && rounded.is_multiple_of(10) {
+                rounded /= 10;
+                scale -= 1;
+            }
+            let integer_digits = rounded.checked_ilog10().map_or(0, |log| log as i32 + 1) - scale;
+            if integer_digits > 3 && tier < options.suffixes.len() as i32 {
+                tier += 1; // Rounding carried over to the next suffix: 999.9K -> 1M
+                continue;
+            }
+            if rounded == 0 {
+                return Ok(String::from("0"));
+            }
+            let mut number = rounded.to_string();
+            if scale < 0 {
+                number.push_str(&"0".repeat(scale.unsigned_abs() as usize));
+                scale = 0;
+            }
+            let scale = scale as usize;
+            if number.len() <= scale {
+                number = format!("{}{}", "0".repeat(scale + 1 - number.len()), number);
+            }
+            let (integer, fraction) = number.split_at(number.len() - scale);
+            let mut result = String::new();
+            if negative {
+                result.push(options.locale.minus_sign);
+            }
+            result.push_str(&options.locale.group_digits(integer));
+            if !fraction.is_empty() {
+                result.push(options.locale.decimal_separator);
+                result.push_str(fraction);
+            }
+            if tier > 0 {
+                result.push_str(options.separator);
+                result.push_str(options.suffixes[tier as usize - 1]);
+            }
+            return Ok(result);
+        }
+    }
+}
+
+#[test]
+fn decimal_compact_works() {
+    let compact = |text: &str, options: &DecimalCompactOptions| Decimal::parse(text).unwrap().to_compact_string(options).unwrap();
+    assert_eq!(compact("1234", &DECIMAL_COMPACT_EN), "1.23K");
+    assert_eq!(compact("3450000", &DECIMAL_COMPACT_EN), "3.45M");
+    assert_eq!(compact("7000000000", &DECIMAL_COMPACT_EN), "7B");
+    assert_eq!(compact("-1200.00", &DECIMAL_COMPACT_EN), "-1.2K");
+    assert_eq!(compact("999.5", &DECIMAL_COMPACT_EN), "1K");
+    assert_eq!(compact("999999", &DECIMAL_COMPACT_EN), "1M");
+    assert_eq!(compact("123.456", &DECIMAL_COMPACT_EN), "123");
+    assert_eq!(compact("0.00123456", &DECIMAL_COMPACT_EN), "0.00123");
+    assert_eq!(compact("12345000000000000", &DECIMAL_COMPACT_EN), "12,300T");
+    assert_eq!(compact("1234", &DECIMAL_COMPACT_RU), "1,23\u{00A0}тыс.");
+    assert_eq!(compact("25600000", &DECIMAL_COMPACT_RU), "25,6\u{00A0}млн");
+    assert_eq!(compact("4500000000", &DECIMAL_COMPACT_DE), "4,5\u{00A0}Mrd.");
+
+    let options = DecimalCompactOptions { significant_digits: 2, rounding: RoundingMode::Down, ..DECIMAL_COMPACT_EN };
+    assert_eq!(compact("1999", &options), "1.9K");
+    assert_eq!(compact("123456", &options), "120K");
+    let options = DecimalCompactOptions { significant_digits: 0, ..DECIMAL_COMPACT_EN };
+    assert!(Decimal::parse("1").unwrap().to_compact_string(&options).is_err());
+}
@@ -0,0 +1,87 @@
+use std::convert::TryFrom;
+
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign};
+
+macro_rules! impl_from_integer {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Decimal {
+                fn from(value: $type) -> Decimal {
+                    let value = value as i128;
+                    let sign = if value < 0 { DecimalSign::Negative } else { DecimalSign::Positive };
+                    // At most 20 digits, always below DECIMAL_MAX
+                    Decimal::from_coefficient(sign, DecimalScale::Scale00, value.unsigned_abs()).unwrap()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl TryFrom<i128> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(value: i128) -> Result<Decimal, DecimalError> {
+        let sign = if value < 0 { DecimalSign::Negative } else { DecimalSign::Positive };
+        Decimal::from_coefficient(sign, DecimalScale::Scale00, value.unsigned_abs())
+    }
+}
+
+impl TryFrom<u128> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(value: u128) -> Result<Decimal, DecimalError> {
+        Decimal::from_coefficient(DecimalSign::Positive, DecimalScale::Scale00, value)
+    }
+}
+
+impl Decimal {
+    // Integer value, fails on a nonzero fractional part
+    fn to_i128_exact(self) -> Result<i128, DecimalError> {
+        let divisor = 10u128.pow(self.scale as u32);
+        if !self.coefficient().is_multiple_of(divisor) {
+            return Err(DecimalError); // Fractional part
+        }
+        let integer = (self.coefficient() / divisor) as i128; // Below 10^38, fits into i128
+        Ok(if self.sign == DecimalSign::Negative { -integer } else { integer })
+    }
+}
+
+macro_rules! impl_try_from_decimal {
+    ($($type:ty),*) => {
+        $(
+            impl TryFrom<Decimal> for $type {
+                type Error = DecimalError;
+
+                fn try_from(value: Decimal) -> Result<$type, DecimalError> {
+                    match <$type>::try_from(value.to_i128_exact()?) {
+                        Ok(value) => Ok(value),
+                        Err(_) => Err(DecimalError), // Overflow
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_decimal!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[test]
+fn decimal_integer_conversion_works() {
+    assert_eq!(Decimal::from(-42i8).to_string(), "-42.0");
+    assert_eq!(Decimal::from(u64::MAX).to_string(), "18446744073709551615.0");
+    assert_eq!(Decimal::from(i64::MIN).to_string(), "-9223372036854775808.0");
+    assert_eq!(Decimal::from(0usize).to_parts(), crate::DECIMAL_ZERO.to_parts());
+    let min = Decimal::try_from(-99999999999999999999999999999999999999i128).unwrap();
+    assert_eq!(i128::try_from(min).unwrap(), -99999999999999999999999999999999999999i128);
+    assert!(Decimal::try_from(100000000000000000000000000000000000000u128).is_err());
+    assert!(Decimal::try_from(i128::MIN).is_err());
+
+    assert_eq!(i32::try_from(Decimal::parse("-1234.000").unwrap()).unwrap(), -1234);
+    assert_eq!(u128::try_from(crate::DECIMAL_MAX).unwrap(), 99999999999999999999999999999999999999u128);
+    assert!(i32::try_from(Decimal::parse("1.5").unwrap()).is_err());
+    assert!(u8::try_from(Decimal::parse("256").unwrap()).is_err());
+    assert!(u64::try_from(Decimal::parse("-1").unwrap()).is_err());
+    assert_eq!(u64::try_from(Decimal::from(7u32).mul(&Decimal::from(6u8)).unwrap()).unwrap(), 42);
+}
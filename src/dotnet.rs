@@ -0,0 +1,89 @@
+use std::fmt;
+
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign};
+
+const DOTNET_MANTISSA_MAX: u128 = (1 << 96) - 1;
+const DOTNET_SCALE_MAX: u32 = 28;
+
+/// Why a value cannot cross over to or from .NET `System.Decimal`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DotNetDecimalError {
+    MantissaOverflow, // More than 96 bits even after dropping trailing zeros
+    ScaleOverflow,    // Scale above 28 even after dropping trailing zeros
+    InvalidFlags,     // Reserved flag bits set or scale above 28 in the flags word
+}
+
+impl fmt::Display for DotNetDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DotNetDecimalError::MantissaOverflow => write!(f, "Mantissa exceeds 96 bits"),
+            DotNetDecimalError::ScaleOverflow => write!(f, "Scale exceeds 28"),
+            DotNetDecimalError::InvalidFlags => write!(f, "Invalid System.Decimal flags"),
+        }
+    }
+}
+
+impl From<DotNetDecimalError> for DecimalError {
+    fn from(_: DotNetDecimalError) -> DecimalError {
+        DecimalError
+    }
+}
+
+impl Decimal {
+    /// `decimal.GetBits` layout: low, middle and high 32 bits of the mantissa, then flags
+    /// with the scale in bits 16..23 and the sign in bit 31. Trailing zeros are dropped only when needed to fit.
+    pub fn to_dotnet_bits(&self) -> Result<[i32; 4], DotNetDecimalError> {
+        let mut mantissa = self.coefficient();
+        let mut scale = self.scale as u32;
+        while (mantissa > DOTNET_MANTISSA_MAX || scale > DOTNET_SCALE_MAX) && scale > 0 && mantissa.is_multiple_of(10) {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        if scale > DOTNET_SCALE_MAX {
+            return Err(DotNetDecimalError::ScaleOverflow);
+        }
+        if mantissa > DOTNET_MANTISSA_MAX {
+            return Err(DotNetDecimalError::MantissaOverflow);
+        }
+        let mut flags = scale << 16;
+        if self.sign == DecimalSign::Negative && mantissa != 0 {
+            flags |= 1 << 31;
+        }
+        Ok([mantissa as u32 as i32, (mantissa >> 32) as u32 as i32, (mantissa >> 64) as u32 as i32, flags as i32])
+    }
+    /// Reads the four words returned by `decimal.GetBits`, as in `new decimal(int[])`.
+    pub fn from_dotnet_bits(bits: [i32; 4]) -> Result<Decimal, DotNetDecimalError> {
+        let flags = bits[3] as u32;
+        let scale = (flags >> 16) & 0xFF;
+        if flags & 0x7F00_FFFF != 0 || scale > DOTNET_SCALE_MAX {
+            return Err(DotNetDecimalError::InvalidFlags);
+        }
+        let mantissa = (bits[2] as u32 as u128) << 64 | (bits[1] as u32 as u128) << 32 | bits[0] as u32 as u128;
+        let sign = if flags >> 31 == 1 { DecimalSign::Negative } else { DecimalSign::Positive };
+        // 96 bits are below 10^29, always within DECIMAL_MAX
+        Ok(Decimal::from_coefficient(sign, DecimalScale::try_from_usize(scale as usize).unwrap(), mantissa).unwrap())
+    }
+}
+
+#[test]
+fn decimal_dotnet_bits_works() {
+    // new decimal(-123.45m).GetBits() == { 12345, 0, 0, -2147352576 }
+    let decimal = Decimal::parse("-123.45").unwrap();
+    assert_eq!(decimal.to_dotnet_bits().unwrap(), [12345, 0, 0, -2147352576]);
+    assert_eq!(Decimal::from_dotnet_bits([12345, 0, 0, -2147352576]).unwrap(), decimal);
+    // decimal.MaxValue == 79228162514264337593543950335
+    let max = Decimal::from_dotnet_bits([-1, -1, -1, 0]).unwrap();
+    assert_eq!(max.mantissa(), 79_228_162_514_264_337_593_543_950_335);
+    assert_eq!(max.to_dotnet_bits().unwrap(), [-1, -1, -1, 0]);
+    assert_eq!(Decimal::from_dotnet_bits([1, 0, 0, 28 << 16]).unwrap().scale(), 28);
+
+    let long_scale = Decimal::from_i128_with_scale(15 * 10i128.pow(30), 38).unwrap(); // 0.15 at scale 38
+    let bits = long_scale.to_dotnet_bits().unwrap();
+    assert_eq!(bits[3], 28 << 16);
+    assert_eq!(Decimal::from_dotnet_bits(bits).unwrap().mantissa(), 15 * 10i128.pow(20));
+    let long_integer = Decimal::from_i128_with_scale(10i128.pow(30), 1).unwrap();
+    assert_eq!(long_integer.to_dotnet_bits(), Err(DotNetDecimalError::MantissaOverflow));
+    assert_eq!(Decimal::from_i128_with_scale(1, 29).unwrap().to_dotnet_bits(), Err(DotNetDecimalError::ScaleOverflow));
+    assert_eq!(Decimal::from_dotnet_bits([1, 0, 0, 29 << 16]), Err(DotNetDecimalError::InvalidFlags));
+    assert_eq!(Decimal::from_dotnet_bits([1, 0, 0, 1]), Err(DotNetDecimalError::InvalidFlags));
+}
@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign, RoundingMode};
+
+/// How `Decimal::from_f64` and `Decimal::from_f32` read a binary float.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloatConversion {
+    Exact,    // True decimal expansion of the binary value, rounded to 38 digits: 0.1 -> 0.10000000000000000555111512312578270212
+    Shortest, // Shortest digits that round-trip, as JS Number.prototype.toString: 0.1 -> 0.1
+}
+
+impl Decimal {
+    pub fn from_f64(value: f64, mode: FloatConversion) -> Result<Decimal, DecimalError> {
+        if !value.is_finite() {
+            return Err(DecimalError); // NaN or infinity
+        }
+        match mode {
+            FloatConversion::Shortest => Decimal::from_scientific(&format!("{:e}", value)),
+            FloatConversion::Exact => {
+                let bits = value.to_bits();
+                let negative = bits >> 63 == 1;
+                let exponent = ((bits >> 52) & 0x7FF) as i32;
+                let fraction = bits & 0x000F_FFFF_FFFF_FFFF;
+                let (mantissa, exponent) = if exponent == 0 { (fraction, -1074) } else { (fraction | 1 << 52, exponent - 1075) };
+                if exponent >= 0 {
+                    // Integer value mantissa * 2^exponent
+                    let digits = match 1u128.checked_shl(exponent as u32).and_then(|power| (mantissa as u128).checked_mul(power)) {
+                        Some(value) => value.to_string(),
+                        None => return Err(DecimalError), // Overflow
+                    };
+                    Decimal::from_digits(negative, &digits, 0)
+                } else {
+                    // mantissa * 2^exponent = mantissa * 5^-exponent / 10^-exponent
+                    let digits = Decimal::mul_pow5(mantissa, exponent.unsigned_abs());
+                    Decimal::from_digits(negative, &digits, exponent.unsigned_abs())
+                }
+            },
+        }
+    }
+    pub fn from_f32(value: f32, mode: FloatConversion) -> Result<Decimal, DecimalError> {
+        match mode {
+            FloatConversion::Exact => Decimal::from_f64(value as f64, mode), // f32 -> f64 is exact
+            FloatConversion::Shortest if value.is_finite() => Decimal::from_scientific(&format!("{:e}", value)),
+            FloatConversion::Shortest => Err(DecimalError), // NaN or infinity
+        }
+    }
+    // Decimal digits of mantissa * 5^power, computed in base 10^9 limbs
+    fn mul_pow5(mantissa: u64, power: u32) -> String {
+        let mut limbs: Vec<u64> = vec![mantissa % 1_000_000_000, mantissa / 1_000_000_000 % 1_000_000_000, mantissa / 1_000_000_000_000_000_000];
+        let mut power = power;
+        while power > 0 {
+            let step = power.min(13); // 5^13 * 10^9 fits into u64
+            let factor = 5u64.pow(step);
+            let mut carry = 0u64;
+            for limb in limbs.iter_mut() {
+                let product = *limb * factor + carry;
+                *limb = product % 1_000_000_000;
+                carry = product / 1_000_000_000;
+            }
+            while carry > 0 {
+                limbs.push(carry % 1_000_000_000);
+                carry /= 1_000_000_000;
+            }
+            power -= step;
+        }
+        let mut digits = String::new();
+        for limb in limbs.iter().rev() {
+            digits.push_str(&format!("{:09}", limb));
+        }
+        let digits = digits.trim_start_matches('0');
+        if digits.is_empty() { String::from("0") } else { String::from(digits) }
+    }
+    // Parses the output of `{:e}` formatting, e.g. -1.2345e-7
+    pub(crate) fn from_scientific(text: &str) -> Result<Decimal, DecimalError> {
+        let (negative, text) = match text.strip_prefix('-') {
+            Some(value) => (true, value),
+            None => (false, text),
+        };
+        let (mantissa, exponent) = match text.split_once('e') {
+            Some((mantissa, exponent)) => match exponent.parse::<i32>() {
+                Ok(exponent) => (mantissa, exponent),
+                Err(_) => return Err(DecimalError),
+            },
+            None => (text, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let mut digits = format!("{}{}", integer, fraction);
+        let scale = fraction.len() as i32 - exponent;
+        if scale < 0 {
+            digits.push_str(&"0".repeat(scale.unsigned_abs() as usize));
+        }
+        Decimal::from_digits(negative, &digits, scale.max(0) as u32)
+    }
+    // Validating parser for untrusted text such as JSON numbers: an optional sign, ASCII digits with at most
+    // one '.', and an optional exponent up to 76 in magnitude. With or without exponent the value must fit
+    // exactly, in 38 digits and scale 38 after dropping trailing fractional zeros; nothing is rounded.
+    pub(crate) fn parse_scientific(text: &str) -> Result<Decimal, DecimalError> {
+        let (negative, unsigned) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(index) => (&unsigned[..index], Some(&unsigned[index + 1..])),
+            None => (unsigned, None),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(DecimalError); // No digits
+        }
+        if !integer.bytes().chain(fraction.bytes()).all(|byte| byte.is_ascii_digit()) {
+            return Err(DecimalError); // Not a digit, second '.' or second sign
+        }
+        let exponent = match exponent {
+            Some(exponent) => {
+                let (exponent_negative, digits) = match exponent.as_bytes().first() {
+                    Some(b'-') => (true, &exponent[1..]),
+                    Some(b'+') => (false, &exponent[1..]),
+                    _ => (false, exponent),
+                };
+                if digits.is_empty() || digits.len() > 3 || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                    return Err(DecimalError); // Missing or too long exponent
+                }
+                let value = digits.parse::<i64>().unwrap();
+                if value > 76 {
+                    return Err(DecimalError); // Exponent out of range
+                }
+                if exponent_negative { -value } else { value }
+            },
+            None => 0,
+        };
+        let mut digits = format!("{}{}", integer, fraction);
+        let mut scale = fraction.len() as i64 - exponent;
+        if scale < 0 {
+            digits.push_str(&"0".repeat(scale.unsigned_abs() as usize));
+            scale = 0;
+        }
+        // Trailing zeros are only dropped when the scale would not fit otherwise, 1.50 keeps scale 2
+        let mut digits = digits.trim_start_matches('0');
+        while scale > 38 && digits.ends_with('0') {
+            digits = &digits[..digits.len() - 1];
+            scale -= 1;
+        }
+        if digits.is_empty() {
+            return Decimal::from_coefficient(DecimalSign::Positive, DecimalScale::Scale00, 0);
+        }
+        if digits.len() > 38 || scale > 38 {
+            return Err(DecimalError); // Does not fit exactly
+        }
+        let sign = if negative { DecimalSign::Negative } else { DecimalSign::Positive };
+        Decimal::from_coefficient(sign, DecimalScale::try_from_usize(scale as usize).unwrap(), digits.parse::<u128>().unwrap())
+    }
+    // digits * 10^-scale rounded half even to the 38 digits and the 38 scale available
+    fn from_digits(negative: bool, digits: &str, scale: u32) -> Result<Decimal, DecimalError> {
+        let mut digits = digits.trim_start_matches('0');
+        let mut scale = scale;
+        while scale > 0 && digits.ends_with('0') {
+            digits = &digits[..digits.len() - 1];
+            scale -= 1;
+        }
+        let integer_digits = (digits.len() as i64 - scale as i64).max(0) as u32;
+        if integer_digits > 38 {
+            return Err(DecimalError); // Overflow
+        }
+        let target_scale = scale.min(38 - integer_digits);
+        let kept_length = (digits.len() as i64 - (scale - target_scale) as i64).max(0) as usize;
+        let (kept, dropped) = digits.split_at(kept_length);
+        let mut coefficient = if kept.is_empty() { 0 } else { kept.parse::<u128>().unwrap() };
+        if dropped.bytes().any(|digit| digit != b'0') {
+            let first = dropped.as_bytes()[0];
+            let half = match first.cmp(&b'5') {
+                Ordering::Equal if dropped[1..].bytes().any(|digit| digit != b'0') => Ordering::Greater,
+                Ordering::Equal => Ordering::Equal,
+                ordering => ordering,
+            };
+            // Implied leading zeros when even the first digit is beyond the available scale
+            let half = if (scale - target_scale) as usize > digits.len() { Ordering::Less } else { half };
+            if RoundingMode::HalfEven.rounds_away(negative, coefficient % 2 == 1, half)? {
+                coefficient += 1;
+            }
+        }
+        // 0.999...9 with 39 nines carries into a 39th digit, one less fractional digit makes room for it
+        let mut target_scale = target_scale;
+        if coefficient > crate::DECIMAL_COEFFICIENT_MAX && target_scale > 0 {
+            coefficient /= 10;
+            target_scale -= 1;
+        }
+        let sign = if negative { DecimalSign::Negative } else { DecimalSign::Positive };
+        Decimal::from_coefficient(sign, DecimalScale::try_from_usize(target_scale as usize).unwrap(), coefficient)
+    }
+}
+
+#[test]
+fn decimal_float_conversion_works() {
+    let plain = crate::DecimalLocale::new('.', None);
+    assert_eq!(Decimal::from_f64(0.1, FloatConversion::Shortest).unwrap().format_locale(&plain), "0.1");
+    assert_eq!(Decimal::from_f64(0.1, FloatConversion::Exact).unwrap().format_locale(&plain), "0.10000000000000000555111512312578270212");
+    assert_eq!(Decimal::from_f64(-1234.5, FloatConversion::Shortest).unwrap().format_locale(&plain), "-1234.5");
+    assert_eq!(Decimal::from_f64(1e21, FloatConversion::Shortest).unwrap().format_locale(&plain), "1000000000000000000000");
+    assert_eq!(Decimal::from_f64(1.0 / 3.0, FloatConversion::Shortest).unwrap().format_locale(&plain), "0.3333333333333333");
+    assert_eq!(Decimal::from_f64(5e-324, FloatConversion::Exact).unwrap().to_parts(), crate::DECIMAL_ZERO.to_parts());
+    assert_eq!(Decimal::from_f64(-0.0, FloatConversion::Exact).unwrap().to_parts(), crate::DECIMAL_ZERO.to_parts());
+    assert_eq!(Decimal::from_f64(2f64.powi(100), FloatConversion::Exact).unwrap().format_locale(&plain), "1267650600228229401496703205376");
+    assert_eq!(Decimal::from_f32(0.1, FloatConversion::Shortest).unwrap().format_locale(&plain), "0.1");
+    assert_eq!(Decimal::from_f32(0.1, FloatConversion::Exact).unwrap().format_locale(&plain), "0.100000001490116119384765625");
+    assert!(Decimal::from_f64(f64::NAN, FloatConversion::Shortest).is_err());
+    assert_eq!(Decimal::from_f64(1e38, FloatConversion::Exact).unwrap().format_locale(&plain), "99999999999999997748809823456034029568");
+    assert!(Decimal::from_f64(1e39, FloatConversion::Exact).is_err());
+    let carried = Decimal::from_digits(false, &"9".repeat(39), 39).unwrap(); // Rounds up to 1
+    assert_eq!((carried.mantissa(), carried.scale()), (10i128.pow(37), 37));
+    let carried = Decimal::from_scientific("-9.999999999999999999999999999999999999999e-1").unwrap();
+    assert_eq!((carried.mantissa(), carried.scale()), (-(10i128.pow(37)), 37));
+
+    assert_eq!(Decimal::parse("0.1").unwrap().to_f64(), 0.1);
+    assert_eq!(Decimal::parse("-1234.5").unwrap().to_f32(), -1234.5f32);
+    assert_eq!(Decimal::parse("9007199254740993").unwrap().to_f64(), 9007199254740992.0); // Ties to even
+    assert_eq!(crate::DECIMAL_MAX.to_f64(), 1e38);
+    for value in [0.1, 123.456, 1e-20, 1.7976931348623157e300 / 1e270, 2.5e-7, 0.30000000000000004] {
+        assert_eq!(Decimal::from_f64(value, FloatConversion::Shortest).unwrap().to_f64(), value);
+    }
+}
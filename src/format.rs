@@ -0,0 +1,392 @@
+use crate::{Decimal, DecimalError, DecimalLocale, DecimalSign, RoundingMode, DECIMAL_LOCALE_EN_US};
+
+/// Pattern compiled once and applied to any `Decimal`, e.g. `#,##0.00;(#,##0.00)`, `0.000%` or `000000.00`.
+///
+/// Supported syntax: `0` and `#` digits, `,` grouping, `.` decimal point, `%` and `‰` scaling,
+/// `'text'`, `"text"` and `\c` literals, `_c` spacing, `*c` padding and an optional negative subpattern after `;`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecimalFormat {
+    positive: DecimalSubpattern,
+    negative: Option<DecimalSubpattern>,
+    rounding: RoundingMode,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PadPosition {
+    BeforePrefix,
+    AfterPrefix,
+    BeforeSuffix,
+    AfterSuffix,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct DecimalSubpattern {
+    prefix: String,
+    suffix: String,
+    minimum_integer_digits: usize,
+    minimum_fraction_digits: usize,
+    maximum_fraction_digits: usize,
+    primary_grouping: u8, // 0 - no grouping
+    secondary_grouping: u8,
+    multiplier_exponent: u32, // 2 for percent, 3 for permille
+    padding: Option<(char, PadPosition)>,
+    width: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PatternPart {
+    Prefix,
+    Integer,
+    Fraction,
+    Suffix,
+}
+
+impl DecimalSubpattern {
+    fn compile(pattern: &str) -> Result<DecimalSubpattern, DecimalError> {
+        let mut subpattern = DecimalSubpattern {
+            prefix: String::new(),
+            suffix: String::new(),
+            minimum_integer_digits: 0,
+            minimum_fraction_digits: 0,
+            maximum_fraction_digits: 0,
+            primary_grouping: 0,
+            secondary_grouping: 0,
+            multiplier_exponent: 0,
+            padding: None,
+            width: 0,
+        };
+        let mut part = PatternPart::Prefix;
+        let mut group_sizes: Vec<u8> = Vec::new();
+        let mut has_grouping = false;
+        let mut chars = pattern.chars();
+        while let Some(char) = chars.next() {
+            let literal = match (char, part) {
+                ('0', PatternPart::Prefix) | ('0', PatternPart::Integer) => {
+                    part = PatternPart::Integer;
+                    subpattern.minimum_integer_digits += 1;
+                    DecimalSubpattern::count_digit(&mut group_sizes)?;
+                    None
+                },
+                ('#', PatternPart::Prefix) | ('#', PatternPart::Integer) => {
+                    if subpattern.minimum_integer_digits > 0 {
+                        return Err(DecimalError); // '#' after '0'
+                    }
+                    part = PatternPart::Integer;
+                    DecimalSubpattern::count_digit(&mut group_sizes)?;
+                    None
+                },
+                (',', PatternPart::Prefix) | (',', PatternPart::Integer) => {
+                    part = PatternPart::Integer;
+                    has_grouping = true;
+                    group_sizes.push(0);
+                    None
+                },
+                ('.', PatternPart::Prefix) | ('.', PatternPart::Integer) => {
+                    part = PatternPart::Fraction;
+                    None
+                },
+                ('0', PatternPart::Fraction) => {
+                    if subpattern.maximum_fraction_digits > subpattern.minimum_fraction_digits {
+                        return Err(DecimalError); // '0' after '#'
+                    }
+                    subpattern.minimum_fraction_digits += 1;
+                    subpattern.maximum_fraction_digits += 1;
+                    None
+                },
+                ('#', PatternPart::Fraction) => {
+                    subpattern.maximum_fraction_digits += 1;
+                    None
+                },
+                ('0', PatternPart::Suffix) | ('#', PatternPart::Suffix) | (',', PatternPart::Suffix) | ('.', PatternPart::Suffix) | (',', PatternPart::Fraction) | ('.', PatternPart::Fraction) => {
+                    return Err(DecimalError); // Second number in the pattern
+                },
+                ('*', _) => {
+                    if subpattern.padding.is_some() {
+                        return Err(DecimalError);
+                    }
+                    let pad = match chars.next() {
+                        Some(value) => value,
+                        None => return Err(DecimalError),
+                    };
+                    let position = match (part, subpattern.prefix.is_empty(), subpattern.suffix.is_empty()) {
+                        (PatternPart::Prefix, true, _) => PadPosition::BeforePrefix,
+                        (PatternPart::Prefix, false, _) => PadPosition::AfterPrefix,
+                        (PatternPart::Suffix, _, false) => PadPosition::AfterSuffix,
+                        (_, _, _) => PadPosition::BeforeSuffix,
+                    };
+                    subpattern.padding = Some((pad, position));
+                    None
+                },
+                ('%', _) | ('\u{2030}', _) => {
+                    if subpattern.multiplier_exponent != 0 {
+                        return Err(DecimalError);
+                    }
+                    subpattern.multiplier_exponent = if char == '%' { 2 } else { 3 };
+                    Some(String::from(char))
+                },
+                ('\'', _) => {
+                    let mut text = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('\'') => break,
+                            Some(value) => text.push(value),
+                            None => return Err(DecimalError), // Unterminated quote
+                        }
+                    }
+                    if text.is_empty() {
+                        text.push('\'');
+                    }
+                    Some(text)
+                },
+                ('"', _) => {
+                    let mut text = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('"') => break,
+                            Some(value) => text.push(value),
+                            None => return Err(DecimalError), // Unterminated quote
+                        }
+                    }
+                    Some(text)
+                },
+                ('\\', _) => match chars.next() {
+                    Some(value) => Some(String::from(value)),
+                    None => return Err(DecimalError),
+                },
+                ('_', _) => match chars.next() {
+                    Some(_) => Some(String::from(' ')), // Excel: space as wide as the next character
+                    None => return Err(DecimalError),
+                },
+                (_, _) => Some(String::from(char)),
+            };
+            if let Some(text) = literal {
+                match part {
+                    PatternPart::Prefix => subpattern.prefix.push_str(&text),
+                    _ => {
+                        part = PatternPart::Suffix;
+                        subpattern.suffix.push_str(&text);
+                    },
+                }
+            } else if part != PatternPart::Prefix && char != '*' {
+                subpattern.width += 1;
+            }
+        }
+        if part == PatternPart::Prefix {
+            return Err(DecimalError); // No digits in the pattern
+        }
+        if has_grouping {
+            match group_sizes.len() {
+                0 | 1 => return Err(DecimalError),
+                length => {
+                    subpattern.primary_grouping = group_sizes[length - 1];
+                    subpattern.secondary_grouping = if length > 2 { group_sizes[length - 2] } else { group_sizes[length - 1] };
+                    if subpattern.primary_grouping == 0 || subpattern.secondary_grouping == 0 {
+                        return Err(DecimalError);
+                    }
+                },
+            }
+        }
+        if subpattern.padding.is_none() {
+            subpattern.width = 0;
+        } else {
+            subpattern.width += subpattern.prefix.chars().count() + subpattern.suffix.chars().count();
+        }
+        Ok(subpattern)
+    }
+    fn count_digit(group_sizes: &mut Vec<u8>) -> Result<(), DecimalError> {
+        match group_sizes.last_mut() {
+            Some(size) if *size == u8::MAX => Err(DecimalError),
+            Some(size) => {
+                *size += 1;
+                Ok(())
+            },
+            None => {
+                group_sizes.push(1);
+                Ok(())
+            },
+        }
+    }
+    fn format(&self, negative: bool, coefficient: u128, scale: i32, locale: &DecimalLocale, minus: bool) -> String {
+        let mut digits = coefficient.to_string();
+        let mut scale = scale;
+        if scale < 0 {
+            digits.push_str(&"0".repeat(scale.unsigned_abs() as usize));
+            scale = 0;
+        }
+        let scale = scale as usize;
+        if digits.len() <= scale {
+            digits = format!("{}{}", "0".repeat(scale + 1 - digits.len()), digits);
+        }
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        let mut fraction = String::from(fraction);
+        while fraction.len() > self.minimum_fraction_digits && fraction.ends_with('0') {
+            fraction.pop();
+        }
+        while fraction.len() < self.minimum_fraction_digits {
+            fraction.push('0');
+        }
+        let mut integer = String::from(integer.trim_start_matches('0'));
+        while integer.len() < self.minimum_integer_digits {
+            integer.insert(0, '0');
+        }
+        if integer.is_empty() && fraction.is_empty() {
+            integer.push('0');
+        }
+        let grouping = DecimalLocale {
+            primary_grouping: self.primary_grouping,
+            secondary_grouping: self.secondary_grouping,
+            ..*locale
+        };
+        let mut number = grouping.group_digits(&integer);
+        if !fraction.is_empty() {
+            number.push(locale.decimal_separator);
+            number.push_str(&fraction);
+        }
+        let mut prefix = String::new();
+        if negative && minus {
+            prefix.push(locale.minus_sign);
+        }
+        prefix.push_str(&self.prefix);
+        let length = prefix.chars().count() + number.chars().count() + self.suffix.chars().count();
+        let padding = match self.padding {
+            Some((pad, position)) if self.width > length => Some((pad.to_string().repeat(self.width - length), position)),
+            _ => None,
+        };
+        let mut result = String::new();
+        if let Some((pad, PadPosition::BeforePrefix)) = &padding {
+            result.push_str(pad);
+        }
+        result.push_str(&prefix);
+        if let Some((pad, PadPosition::AfterPrefix)) = &padding {
+            result.push_str(pad);
+        }
+        result.push_str(&number);
+        if let Some((pad, PadPosition::BeforeSuffix)) = &padding {
+            result.push_str(pad);
+        }
+        result.push_str(&self.suffix);
+        if let Some((pad, PadPosition::AfterSuffix)) = &padding {
+            result.push_str(pad);
+        }
+        result
+    }
+}
+
+impl DecimalFormat {
+    pub fn new(pattern: &str, rounding: RoundingMode) -> Result<DecimalFormat, DecimalError> {
+        let mut subpatterns = DecimalFormat::split_subpatterns(pattern).into_iter();
+        let positive = DecimalSubpattern::compile(subpatterns.next().unwrap_or_default())?;
+        let negative = match subpatterns.next() {
+            Some(value) => Some(DecimalSubpattern::compile(value)?),
+            None => None,
+        };
+        if subpatterns.next().is_some() {
+            return Err(DecimalError); // Only positive and negative subpatterns are supported
+        }
+        Ok(DecimalFormat { positive, negative, rounding })
+    }
+    // Splits on ';' outside quotes, escapes and the character after '*' or '_'
+    fn split_subpatterns(pattern: &str) -> Vec<&str> {
+        let mut result = Vec::new();
+        let mut start = 0;
+        let mut quote: Option<char> = None;
+        let mut chars = pattern.char_indices();
+        while let Some((index, char)) = chars.next() {
+            match (char, quote) {
+                (_, Some(value)) if char == value => quote = None,
+                (_, Some(_)) => {},
+                ('\'', None) | ('"', None) => quote = Some(char),
+                ('\\', None) | ('*', None) | ('_', None) => {
+                    chars.next();
+                },
+                (';', None) => {
+                    result.push(&pattern[start..index]);
+                    start = index + 1;
+                },
+                (_, None) => {},
+            }
+        }
+        result.push(&pattern[start..]);
+        result
+    }
+    pub fn format(&self, value: &Decimal) -> Result<String, DecimalError> {
+        self.format_locale(value, &DECIMAL_LOCALE_EN_US)
+    }
+    /// Formats with the decimal and group separators and the minus sign of `locale`.
+    pub fn format_locale(&self, value: &Decimal, locale: &DecimalLocale) -> Result<String, DecimalError> {
+        let negative = value.sign == DecimalSign::Negative && value.coefficient() != 0;
+        let subpattern = match (negative, &self.negative) {
+            (true, Some(value)) => value,
+            (_, _) => &self.positive,
+        };
+        let scale = value.scale as i32 - subpattern.multiplier_exponent as i32;
+        let (coefficient, scale) = if scale > subpattern.maximum_fraction_digits as i32 {
+            let digits = (scale - subpattern.maximum_fraction_digits as i32) as u32;
+            (self.rounding.round(value.coefficient(), digits, negative)?, subpattern.maximum_fraction_digits as i32)
+        } else {
+            (value.coefficient(), scale)
+        };
+        if coefficient == 0 {
+            return Ok(self.positive.format(false, 0, scale, locale, false));
+        }
+        Ok(subpattern.format(negative, coefficient, scale, locale, self.negative.is_none()))
+    }
+}
+
+#[test]
+fn decimal_format_works() {
+    let format = DecimalFormat::new("#,##0.00;(#,##0.00)", RoundingMode::HalfEven).unwrap();
+    assert_eq!(format.format(&Decimal::parse("1234567.125").unwrap()).unwrap(), "1,234,567.12");
+    assert_eq!(format.format(&Decimal::parse("-1234.5").unwrap()).unwrap(), "(1,234.50)");
+    assert_eq!(format.format(&Decimal::parse("-0.001").unwrap()).unwrap(), "0.00");
+    assert_eq!(format.format_locale(&Decimal::parse("1234.5").unwrap(), &crate::DECIMAL_LOCALE_DE_DE).unwrap(), "1.234,50");
+
+    let format = DecimalFormat::new("0.000%", RoundingMode::HalfUp).unwrap();
+    assert_eq!(format.format(&Decimal::parse("0.123456").unwrap()).unwrap(), "12.346%");
+    assert_eq!(format.format(&Decimal::parse("-5").unwrap()).unwrap(), "-500.000%");
+
+    let format = DecimalFormat::new("#,##0\u{2030}", RoundingMode::Down).unwrap();
+    assert_eq!(format.format(&Decimal::parse("12.3459").unwrap()).unwrap(), "12,345\u{2030}");
+
+    let format = DecimalFormat::new("000000.00", RoundingMode::HalfEven).unwrap();
+    assert_eq!(format.format(&Decimal::parse("42.5").unwrap()).unwrap(), "000042.50");
+
+    let format = DecimalFormat::new("'#'#.##", RoundingMode::HalfEven).unwrap();
+    assert_eq!(format.format(&Decimal::parse("0.5").unwrap()).unwrap(), "#.5");
+    assert_eq!(format.format(&Decimal::parse("7").unwrap()).unwrap(), "#7");
+
+    let format = DecimalFormat::new("$*x#,##0.00", RoundingMode::HalfEven).unwrap();
+    assert_eq!(format.format(&Decimal::parse("123").unwrap()).unwrap(), "$xx123.00");
+    assert_eq!(format.format(&Decimal::parse("1234").unwrap()).unwrap(), "$1,234.00");
+
+    let format = DecimalFormat::new("#,##,##0.0 \"INR\"", RoundingMode::Ceiling).unwrap();
+    assert_eq!(format.format(&Decimal::parse("1234567.01").unwrap()).unwrap(), "12,34,567.1 INR");
+
+    let format = DecimalFormat::new("0.00", RoundingMode::Unnecessary).unwrap();
+    assert!(format.format(&Decimal::parse("1.005").unwrap()).is_err());
+    assert_eq!(format.format(&Decimal::parse("1.5").unwrap()).unwrap(), "1.50");
+
+    let format = DecimalFormat::new("#,##0.00_);(#,##0.00)", RoundingMode::HalfEven).unwrap();
+    assert_eq!(format.format(&Decimal::parse("1000").unwrap()).unwrap(), "1,000.00 ");
+
+    let format = DecimalFormat::new("0 'a;b';0 \\;-", RoundingMode::HalfEven).unwrap();
+    assert_eq!(format.format(&Decimal::parse("7").unwrap()).unwrap(), "7 a;b");
+    assert_eq!(format.format(&Decimal::parse("-7").unwrap()).unwrap(), "7 ;-");
+    assert!(DecimalFormat::new("0 'a;b'", RoundingMode::HalfEven).is_ok());
+
+    assert!(DecimalFormat::new("'abc", RoundingMode::HalfEven).is_err());
+    assert!(DecimalFormat::new("0.00;0.00;0", RoundingMode::HalfEven).is_err());
+    assert!(DecimalFormat::new("text", RoundingMode::HalfEven).is_err());
+}
+
+#[test]
+fn decimal_rescale_works() {
+    let value = Decimal::parse("-2.5").unwrap();
+    assert_eq!(value.rescale(crate::DecimalScale::Scale00, RoundingMode::HalfEven).unwrap().to_string(), "-2.0");
+    assert_eq!(value.rescale(crate::DecimalScale::Scale00, RoundingMode::HalfUp).unwrap().to_string(), "-3.0");
+    assert_eq!(value.rescale(crate::DecimalScale::Scale00, RoundingMode::Floor).unwrap().to_string(), "-3.0");
+    assert_eq!(value.rescale(crate::DecimalScale::Scale00, RoundingMode::Ceiling).unwrap().to_string(), "-2.0");
+    assert_eq!(value.rescale(crate::DecimalScale::Scale03, RoundingMode::Unnecessary).unwrap().to_string(), "-2.500");
+    assert!(value.rescale(crate::DecimalScale::Scale00, RoundingMode::Unnecessary).is_err());
+    assert!(crate::DECIMAL_MAX.rescale(crate::DecimalScale::Scale01, RoundingMode::HalfEven).is_err());
+}
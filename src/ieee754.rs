@@ -0,0 +1,222 @@
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign, RoundingMode};
+
+const DECIMAL128_DIGITS: u32 = 34;
+const DECIMAL128_BIAS: i32 = 6176;
+
+// Interchange format parameters of the densely packed decimal encoding
+struct DpdFormat {
+    width: u32,         // Total bits
+    digits: u32,        // Coefficient digits
+    bias: i32,          // Exponent bias of the integer coefficient
+    continuation: u32,  // Exponent continuation bits after the combination field
+}
+
+const DPD_DECIMAL64: DpdFormat = DpdFormat { width: 64, digits: 16, bias: 398, continuation: 8 };
+const DPD_DECIMAL128: DpdFormat = DpdFormat { width: 128, digits: DECIMAL128_DIGITS, bias: DECIMAL128_BIAS, continuation: 12 };
+
+impl Decimal {
+    /// IEEE 754-2008 decimal128 in the Binary Integer Decimal encoding, little-endian as in BSON `Decimal128`.
+    /// Coefficients above 34 digits are rounded with `mode`.
+    pub fn to_decimal128_bid(&self, mode: RoundingMode) -> Result<[u8; 16], DecimalError> {
+        let (coefficient, exponent) = self.ieee754_coefficient(DECIMAL128_DIGITS, mode)?;
+        let mut bits = ((exponent + DECIMAL128_BIAS) as u128) << 113 | coefficient;
+        if self.sign == DecimalSign::Negative {
+            bits |= 1 << 127;
+        }
+        Ok(bits.to_le_bytes())
+    }
+    /// Reads a BID decimal128, NaN and infinities are errors, digits beyond scale 38 are rounded with `mode`.
+    pub fn from_decimal128_bid(bytes: [u8; 16], mode: RoundingMode) -> Result<Decimal, DecimalError> {
+        let bits = u128::from_le_bytes(bytes);
+        let negative = bits >> 127 == 1;
+        let (exponent, coefficient) = match bits >> 125 & 0b11 {
+            0b11 if bits >> 122 & 0b11111 >= 0b11110 => return Err(DecimalError), // NaN or infinity
+            0b11 => ((bits >> 111 & 0x3FFF) as i32, 0), // 0b100 implied prefix, always above 34 digits: non-canonical zero
+            _ => ((bits >> 113 & 0x3FFF) as i32, bits & ((1 << 113) - 1)),
+        };
+        let coefficient = if coefficient >= 10u128.pow(DECIMAL128_DIGITS) { 0 } else { coefficient }; // Non-canonical
+        Decimal::from_ieee754(negative, coefficient, exponent - DECIMAL128_BIAS, mode)
+    }
+    /// IEEE 754-2008 decimal64 in the Densely Packed Decimal encoding, as a 64-bit pattern
+    /// (`to_be_bytes` gives the z/Architecture byte order). Coefficients above 16 digits are rounded with `mode`.
+    pub fn to_decimal64_dpd(&self, mode: RoundingMode) -> Result<u64, DecimalError> {
+        Ok(self.dpd_bits(&DPD_DECIMAL64, mode)? as u64)
+    }
+    pub fn from_decimal64_dpd(bits: u64, mode: RoundingMode) -> Result<Decimal, DecimalError> {
+        Decimal::from_dpd(&DPD_DECIMAL64, bits as u128, mode)
+    }
+    /// IEEE 754-2008 decimal128 in the Densely Packed Decimal encoding, coefficients above 34 digits are rounded with `mode`.
+    pub fn to_decimal128_dpd(&self, mode: RoundingMode) -> Result<u128, DecimalError> {
+        self.dpd_bits(&DPD_DECIMAL128, mode)
+    }
+    pub fn from_decimal128_dpd(bits: u128, mode: RoundingMode) -> Result<Decimal, DecimalError> {
+        Decimal::from_dpd(&DPD_DECIMAL128, bits, mode)
+    }
+    fn dpd_bits(&self, format: &DpdFormat, mode: RoundingMode) -> Result<u128, DecimalError> {
+        let (coefficient, exponent) = self.ieee754_coefficient(format.digits, mode)?;
+        let exponent = (exponent + format.bias) as u128;
+        let trailing_bits = format.width - 6 - format.continuation;
+        let power = 10u128.pow(format.digits - 1);
+        let (leading, mut rest) = ((coefficient / power) as u32, coefficient % power);
+        let mut declets = 0u128;
+        for index in 0..(format.digits - 1) / 3 {
+            declets |= (encode_declet((rest % 1000) as u32) as u128) << (10 * index);
+            rest /= 1000;
+        }
+        let high = (exponent >> format.continuation) as u32;
+        let combination = if leading < 8 { high << 3 | leading } else { 0b11000 | high << 1 | (leading & 1) };
+        let mut bits = (combination as u128) << (format.width - 6) | (exponent & ((1 << format.continuation) - 1)) << trailing_bits | declets;
+        if self.sign == DecimalSign::Negative {
+            bits |= 1 << (format.width - 1);
+        }
+        Ok(bits)
+    }
+    fn from_dpd(format: &DpdFormat, bits: u128, mode: RoundingMode) -> Result<Decimal, DecimalError> {
+        let negative = bits >> (format.width - 1) & 1 == 1;
+        let combination = (bits >> (format.width - 6) & 0b11111) as u32;
+        let (high, leading) = match combination >> 3 {
+            0b11 if combination >> 1 == 0b1111 => return Err(DecimalError), // NaN or infinity
+            0b11 => (combination >> 1 & 0b11, 8 + (combination & 1)),
+            _ => (combination >> 3, combination & 0b111),
+        };
+        let trailing_bits = format.width - 6 - format.continuation;
+        let exponent = (high << format.continuation) as i32 | (bits >> trailing_bits & ((1 << format.continuation) - 1)) as i32;
+        let mut coefficient = leading as u128;
+        for index in (0..(format.digits - 1) / 3).rev() {
+            coefficient = coefficient * 1000 + decode_declet((bits >> (10 * index) & 0x3FF) as u32) as u128;
+        }
+        Decimal::from_ieee754(negative, coefficient, exponent - format.bias, mode)
+    }
+    // Coefficient of at most `digits` digits and its power of ten
+    fn ieee754_coefficient(&self, digits: u32, mode: RoundingMode) -> Result<(u128, i32), DecimalError> {
+        let mut coefficient = self.coefficient();
+        let mut exponent = -(self.scale as i32);
+        let limit = 10u128.pow(digits);
+        if coefficient >= limit {
+            let dropped = coefficient.ilog10() + 1 - digits;
+            coefficient = mode.round(coefficient, dropped, self.sign == DecimalSign::Negative)?;
+            exponent += dropped as i32;
+            if coefficient == limit {
+                coefficient /= 10; // Carry into a new digit: 99.99 -> 100.0
+                exponent += 1;
+            }
+        }
+        Ok((coefficient, exponent))
+    }
+    // coefficient * 10^exponent, digits beyond scale 38 are rounded with `mode`
+    fn from_ieee754(negative: bool, coefficient: u128, exponent: i32, mode: RoundingMode) -> Result<Decimal, DecimalError> {
+        let sign = if negative { DecimalSign::Negative } else { DecimalSign::Positive };
+        if coefficient == 0 {
+            return Decimal::from_coefficient(sign, DecimalScale::try_from_usize(exponent.clamp(-38, 0).unsigned_abs() as usize).unwrap(), 0);
+        }
+        if exponent >= 0 {
+            match 10u128.checked_pow(exponent as u32).and_then(|factor| coefficient.checked_mul(factor)) {
+                Some(value) => Decimal::from_coefficient(sign, DecimalScale::Scale00, value),
+                None => Err(DecimalError), // Overflow
+            }
+        } else if exponent < -38 {
+            let coefficient = mode.round(coefficient, (-38 - exponent) as u32, negative)?;
+            Decimal::from_coefficient(sign, DecimalScale::Scale38, coefficient)
+        } else {
+            Decimal::from_coefficient(sign, DecimalScale::try_from_usize(exponent.unsigned_abs() as usize).unwrap(), coefficient)
+        }
+    }
+}
+
+// Three decimal digits into ten bits, bit names as in IEEE 754-2008 table 3.4
+fn encode_declet(value: u32) -> u32 {
+    let (d1, d2, d3) = (value / 100, value / 10 % 10, value % 10);
+    let (a, e, i) = (d1 >> 3, d2 >> 3, d3 >> 3);
+    let (bcd, fgh, jkm) = (d1 & 7, d2 & 7, d3 & 7);
+    let (d, h, m) = (d1 & 1, d2 & 1, d3 & 1);
+    let (jk, fg) = (jkm >> 1, fgh >> 1);
+    match (a, e, i) {
+        (0, 0, 0) => bcd << 7 | fgh << 4 | jkm,
+        (0, 0, 1) => bcd << 7 | fgh << 4 | 0b1000 | m,
+        (0, 1, 0) => bcd << 7 | jk << 5 | h << 4 | 0b1010 | m,
+        (1, 0, 0) => jk << 8 | d << 7 | fgh << 4 | 0b1100 | m,
+        (1, 1, 0) => jk << 8 | d << 7 | h << 4 | 0b1110 | m,
+        (1, 0, 1) => fg << 8 | d << 7 | 0b01 << 5 | h << 4 | 0b1110 | m,
+        (0, 1, 1) => bcd << 7 | 0b10 << 5 | h << 4 | 0b1110 | m,
+        _ => d << 7 | 0b11 << 5 | h << 4 | 0b1110 | m,
+    }
+}
+
+fn decode_declet(declet: u32) -> u32 {
+    let (pq, pqr, r) = (declet >> 8 & 3, declet >> 7 & 7, declet >> 7 & 1);
+    let (st, stu, u) = (declet >> 5 & 3, declet >> 4 & 7, declet >> 4 & 1);
+    let (v, wx, y) = (declet >> 3 & 1, declet >> 1 & 3, declet & 1);
+    let (d1, d2, d3) = match (v, wx, st) {
+        (0, _, _) => (pqr, stu, declet & 7),
+        (_, 0b00, _) => (pqr, stu, 8 | y),
+        (_, 0b01, _) => (pqr, 8 | u, st << 1 | y),
+        (_, 0b10, _) => (8 | r, stu, pq << 1 | y),
+        (_, _, 0b00) => (8 | r, 8 | u, pq << 1 | y),
+        (_, _, 0b01) => (8 | r, pq << 1 | u, 8 | y),
+        (_, _, 0b10) => (pqr, 8 | u, 8 | y),
+        _ => (8 | r, 8 | u, 8 | y),
+    };
+    d1 * 100 + d2 * 10 + d3
+}
+
+#[test]
+fn decimal_decimal128_bid_works() {
+    let bid = |decimal: &Decimal| u128::from_le_bytes(decimal.to_decimal128_bid(RoundingMode::HalfEven).unwrap());
+    let read = |bits: u128| Decimal::from_decimal128_bid(bits.to_le_bytes(), RoundingMode::HalfEven);
+    assert_eq!(bid(&Decimal::parse("1").unwrap()), 0x3040_0000_0000_0000_0000_0000_0000_0001);
+    assert_eq!(bid(&Decimal::parse("0.1").unwrap()), 0x303E_0000_0000_0000_0000_0000_0000_0001);
+    assert_eq!(bid(&Decimal::parse("-1.00").unwrap()), 0xB03C_0000_0000_0000_0000_0000_0000_0064);
+    assert_eq!(bid(&Decimal::parse("9999999999999999999999999999999999").unwrap()), 0x3041_ED09_BEAD_87C0_378D_8E63_FFFF_FFFF);
+    // 38 digits round to 34: 1234567890123456789012345678901234.5678 -> ...1235
+    let long = Decimal::parse("1234567890123456789012345678901234.5678").unwrap();
+    assert_eq!(read(bid(&long)).unwrap().to_parts(), Decimal::parse("1234567890123456789012345678901235").unwrap().to_parts());
+    assert!(long.to_decimal128_bid(RoundingMode::Unnecessary).is_err());
+    assert_eq!(bid(&crate::DECIMAL_MAX) >> 113, 6176 + 5); // Carries to 10^33 * 10^5
+
+    assert_eq!(read(0x3040_0000_0000_0000_0000_0000_0000_0001).unwrap().mantissa(), 1);
+    assert_eq!(read(0xB03C_0000_0000_0000_0000_0000_0000_0064).unwrap(), Decimal::parse("-1.00").unwrap());
+    assert_eq!(read(0x3044_0000_0000_0000_0000_0000_0000_0007).unwrap().mantissa(), 700); // 7E+2
+    assert_eq!(read(0x2FF0_0000_0000_0000_0000_0000_0000_015F).unwrap().mantissa(), 4); // 351E-40 -> 4E-38
+    assert!(read(0x7C00_0000_0000_0000_0000_0000_0000_0000).is_err()); // NaN
+    assert!(read(0x7800_0000_0000_0000_0000_0000_0000_0000).is_err()); // Infinity
+    assert!(read(0x5FFF_ED09_BEAD_87C0_378D_8E63_FFFF_FFFF).is_err()); // 9.99...E+6144
+    assert_eq!(read(0x6C10_0000_0000_0000_0000_0000_0000_0001).unwrap().mantissa(), 0); // Non-canonical
+    assert!(Decimal::from_decimal128_bid(0x2FF0_0000_0000_0000_0000_0000_0000_015Fu128.to_le_bytes(), RoundingMode::Unnecessary).is_err());
+}
+
+#[test]
+fn decimal_dpd_works() {
+    // Declets from IEEE 754-2008 table 3.3
+    for (value, declet) in [(0, 0x000), (9, 0x009), (80, 0x00A), (99, 0x05F), (555, 0x2D5), (888, 0x06E), (999, 0x0FF)] {
+        assert_eq!(encode_declet(value), declet);
+        assert_eq!(decode_declet(declet), value);
+    }
+    for value in 0..1000 {
+        assert_eq!(decode_declet(encode_declet(value)), value);
+    }
+    assert_eq!(decode_declet(0x3FF), 999); // Non-canonical
+
+    let one = Decimal::parse("1").unwrap();
+    assert_eq!(one.to_decimal64_dpd(RoundingMode::HalfEven).unwrap(), 0x2238_0000_0000_0001);
+    assert_eq!(one.to_decimal128_dpd(RoundingMode::HalfEven).unwrap(), 0x2208_0000_0000_0000_0000_0000_0000_0001);
+    let decimal = Decimal::parse("-7.50").unwrap();
+    assert_eq!(decimal.to_decimal64_dpd(RoundingMode::HalfEven).unwrap(), 0xA230_0000_0000_03D0);
+    assert_eq!(Decimal::from_decimal64_dpd(0xA230_0000_0000_03D0, RoundingMode::HalfEven).unwrap(), decimal);
+    assert_eq!(Decimal::from_decimal128_dpd(0x2208_0000_0000_0000_0000_0000_0000_0001, RoundingMode::HalfEven).unwrap().mantissa(), 1);
+
+    // 9999999999999999 = 9.999999999999999E+15: leading 9 in the combination field
+    let nines = Decimal::parse("9999999999999999").unwrap();
+    let bits = nines.to_decimal64_dpd(RoundingMode::HalfEven).unwrap();
+    assert_eq!(bits, 0x6E38_FF3F_CFF3_FCFF);
+    assert_eq!(Decimal::from_decimal64_dpd(bits, RoundingMode::HalfEven).unwrap(), nines);
+    let long = Decimal::parse("-12345678901234567890.123456789").unwrap();
+    let bits = long.to_decimal64_dpd(RoundingMode::HalfEven).unwrap();
+    assert_eq!(Decimal::from_decimal64_dpd(bits, RoundingMode::HalfEven).unwrap().mantissa(), -12345678901234570000);
+    assert!(long.to_decimal64_dpd(RoundingMode::Unnecessary).is_err());
+    let bits = long.to_decimal128_dpd(RoundingMode::Unnecessary).unwrap();
+    assert_eq!(Decimal::from_decimal128_dpd(bits, RoundingMode::Unnecessary).unwrap(), long);
+
+    assert!(Decimal::from_decimal64_dpd(0x7800_0000_0000_0000, RoundingMode::HalfEven).is_err()); // Infinity
+    assert!(Decimal::from_decimal64_dpd(0x7C00_0000_0000_0000, RoundingMode::HalfEven).is_err()); // NaN
+    assert!(Decimal::from_decimal128_dpd(0x7C00 << 112, RoundingMode::HalfEven).is_err());
+}
@@ -2,12 +2,53 @@
 #![feature(const_bigint_helper_methods)]
 
 mod utils;
+mod locale;
+mod format;
+mod compact;
+mod money;
+mod allocate;
+mod convert;
+mod float;
+mod sqlserver;
+mod dotnet;
+mod ieee754;
+mod packed;
+mod zoned;
+mod postgres;
+mod mysql;
+mod oracle;
+mod twos_complement;
+mod cbor;
+mod protobuf;
+mod sortable;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use compact::{DecimalCompactOptions, DECIMAL_COMPACT_DE, DECIMAL_COMPACT_EN, DECIMAL_COMPACT_RU};
+pub use dotnet::DotNetDecimalError;
+pub use float::FloatConversion;
+pub use format::DecimalFormat;
+pub use money::{Currency, Money, CURRENCIES};
+pub use packed::PackedBcdError;
+pub use protobuf::{ProtoDecimal, ProtoMoney};
+pub use zoned::{ZonedCharset, ZonedDecimalError, ZonedSign};
+#[cfg(feature = "wasm")]
+pub use wasm::{calculation, greet};
+pub use locale::{
+    CurrencyPosition, DecimalLocale, DECIMAL_LOCALE_DE_CH, DECIMAL_LOCALE_DE_DE, DECIMAL_LOCALE_EN_US,
+    DECIMAL_LOCALE_FR_FR, DECIMAL_LOCALE_RU_RU,
+};
 
 use core::panic;
 use std::convert::TryFrom;
 use std::{fmt, result};
 use std::ops::Add;
 use std::cmp::Ordering;
+#[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
@@ -16,42 +57,7 @@ use wasm_bindgen::prelude::*;
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
-#[wasm_bindgen]
-extern "C" {
-    fn alert(s: &str);
-}
-
-#[wasm_bindgen]
-pub fn greet() {
-    alert("Hello, wasm-decimal!");
-}
-
-#[wasm_bindgen]
-pub fn calculation(operand1: String, operand2: String, operation: i32) -> String {
-    let decimal_operand1 = Decimal::parse(&operand1).unwrap();
-    let decimal_operand2 = Decimal::parse(&operand2).unwrap();
-    match operation {
-        0 => {
-            let result = decimal_operand1.add(&decimal_operand2);
-            result.to_string()
-        },
-        1 => {
-            let result = decimal_operand1.sub(&decimal_operand2);
-            result.to_string()
-        },
-        2 => {
-            let result = decimal_operand1.mul(&decimal_operand2).unwrap();
-            result.to_string()
-        },
-        4 => {
-            let result = decimal_operand1.div(&decimal_operand2);
-            result.to_string()
-        },
-        _ => String::from("0"),
-    }
-}
-
-#[wasm_bindgen]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DecimalSign {
@@ -475,7 +481,9 @@ impl DecimalScale {
 //     }
 // }
 
-#[wasm_bindgen]
+// 20 bytes: the four words plus the sign, precision and scale bytes. 16 bytes are not possible,
+// 10^38 - 1 takes 127 bits and leaves no room for the 6 bits of scale.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Decimal {
     sign: DecimalSign,
@@ -534,6 +542,8 @@ pub const DECIMAL_MAX: Decimal = Decimal {
 
 pub const DECIMAL_MAX_STR: &str = "99999999999999999999999999999999999999";
 
+const DECIMAL_COEFFICIENT_MAX: u128 = 99_999_999_999_999_999_999_999_999_999_999_999_999;
+
 pub const DECIMAL_01: &[Decimal] = &[ // Remove
     Decimal { sign: DecimalSign::Positive, precision: DecimalPrecision::Precision38, scale: DecimalScale::Scale01, part_3: 0x00000000, part_2: 0x00000000, part_1: 0x00000000, part_0: 0x00000001,},
     Decimal { sign: DecimalSign::Positive, precision: DecimalPrecision::Precision38, scale: DecimalScale::Scale02, part_3: 0x00000000, part_2: 0x00000000, part_1: 0x00000000, part_0: 0x00000001,},
@@ -4192,16 +4202,57 @@ pub const DECIMAL_DIGITS: &[&[Decimal]] = &[
     ],
 ];
 
-#[wasm_bindgen]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub enum DecimalComparison {
     GT,
     EQ,
     LT,
 }
 
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    Up,          // Away from zero
+    Down,        // Towards zero
+    Ceiling,     // Towards positive infinity
+    Floor,       // Towards negative infinity
+    HalfUp,      // Nearest, ties away from zero
+    HalfDown,    // Nearest, ties towards zero
+    HalfEven,    // Nearest, ties to even (banker's rounding)
+    Unnecessary, // Exact result is required, rounding fails with DecimalError
+}
+
+impl RoundingMode {
+    // Whether the kept part has to be incremented, `half` compares the nonzero dropped part with one half
+    pub(crate) fn rounds_away(self, negative: bool, odd: bool, half: Ordering) -> Result<bool, DecimalError> {
+        match self {
+            RoundingMode::Up => Ok(true),
+            RoundingMode::Down => Ok(false),
+            RoundingMode::Ceiling => Ok(!negative),
+            RoundingMode::Floor => Ok(negative),
+            RoundingMode::HalfUp => Ok(half != Ordering::Less),
+            RoundingMode::HalfDown => Ok(half == Ordering::Greater),
+            RoundingMode::HalfEven => Ok(half == Ordering::Greater || (half == Ordering::Equal && odd)),
+            RoundingMode::Unnecessary => Err(DecimalError),
+        }
+    }
+    // Drops `digits` least significant decimal digits of the coefficient
+    pub(crate) fn round(self, coefficient: u128, digits: u32, negative: bool) -> Result<u128, DecimalError> {
+        let (kept, dropped, half) = match 10u128.checked_pow(digits) {
+            Some(divisor) => (coefficient / divisor, coefficient % divisor, (coefficient % divisor).cmp(&(divisor / 2))),
+            None => (0, coefficient, Ordering::Less), // 10^39 and above exceed any coefficient
+        };
+        if dropped != 0 && self.rounds_away(negative, kept % 2 == 1, half)? {
+            Ok(kept + 1)
+        } else {
+            Ok(kept)
+        }
+    }
+}
+
 //type DecimalResult<T> = std::result::Result<T, DecimalError>;
 
-#[wasm_bindgen]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 #[derive(Debug, Clone)]
 pub struct DecimalError;
 
@@ -4479,6 +4530,29 @@ fn decimal_mixed_works() {
 
 }
 
+#[test]
+fn decimal_layout_works() {
+    assert_eq!(std::mem::size_of::<Decimal>(), 20);
+}
+
+#[test]
+fn decimal_mantissa_scale_works() {
+    let decimal = Decimal::from_i128_with_scale(-123456, 3).unwrap();
+    assert_eq!(decimal.mantissa(), -123456);
+    assert_eq!(decimal.scale(), 3);
+    assert_eq!(decimal.to_parts(), Decimal::parse("-123.456").unwrap().to_parts());
+    assert_eq!(Decimal::from_i128_with_scale(99_999_999_999_999_999_999_999_999_999_999_999_999, 38).unwrap().mantissa(), 99_999_999_999_999_999_999_999_999_999_999_999_999);
+    assert!(Decimal::from_i128_with_scale(100_000_000_000_000_000_000_000_000_000_000_000_000, 0).is_err());
+    assert!(Decimal::from_i128_with_scale(1, 39).is_err());
+    assert!(Decimal::from_i128_with_scale(i128::MIN, 0).is_err());
+
+    let decimal = Decimal::parse("2000000000002").unwrap();
+    assert_eq!(Decimal::from_parts(decimal.to_parts()).unwrap(), decimal);
+    assert_eq!(Decimal::from_parts(DECIMAL_MIN.to_parts()).unwrap(), DECIMAL_MIN);
+    assert!(Decimal::from_parts((DecimalSign::Positive, DecimalPrecision::Precision38, DecimalScale::Scale00, u32::MAX, u32::MAX, u32::MAX, u32::MAX)).is_err());
+    assert!(Decimal::from_parts((DecimalSign::Positive, DecimalPrecision::Precision02, DecimalScale::Scale00, 1000, 0, 0, 0)).is_err());
+}
+
 impl Decimal {
     pub fn new(
         sign: DecimalSign,
@@ -4679,7 +4753,17 @@ impl Decimal {
         ))
     }
     pub fn to_f64(&self) -> f64 {
-        todo!()
+        // Rust float parsing is correctly rounded
+        self.scientific_string().parse::<f64>().unwrap()
+    }
+    pub fn to_f32(&self) -> f32 {
+        self.scientific_string().parse::<f32>().unwrap()
+    }
+    fn scientific_string(&self) -> String {
+        match self.sign {
+            DecimalSign::Negative => format!("-{}e-{}", self.coefficient(), self.scale as u32),
+            DecimalSign::Positive => format!("{}e-{}", self.coefficient(), self.scale as u32),
+        }
     }
     pub fn to_parts(&self) -> DecimalParts {
         (
@@ -4692,6 +4776,71 @@ impl Decimal {
             self.part_3,
         )
     }
+    /// Mirror of `to_parts`, fails when the limbs exceed `DECIMAL_MAX` or do not fit the precision.
+    pub fn from_parts(parts: DecimalParts) -> Result<Decimal, DecimalError> {
+        let (sign, precision, scale, part_0, part_1, part_2, part_3) = parts;
+        let decimal = Decimal::new(sign, precision, scale, part_0, part_1, part_2, part_3);
+        let coefficient = decimal.coefficient();
+        if coefficient > DECIMAL_COEFFICIENT_MAX {
+            return Err(DecimalError); // Overflow
+        }
+        let digits = coefficient.checked_ilog10().map_or(1, |log| log as usize + 1);
+        if digits > DecimalPrecision::try_to_usize(decimal.precision).unwrap() {
+            return Err(DecimalError); // More digits than the precision allows
+        }
+        Ok(decimal)
+    }
+    /// Unscaled value and number of fractional digits: 123.45 is `from_i128_with_scale(12345, 2)`.
+    pub fn from_i128_with_scale(mantissa: i128, scale: u8) -> Result<Decimal, DecimalError> {
+        if scale > 38 {
+            return Err(DecimalError); // Scale above 38
+        }
+        let scale = DecimalScale::try_from_usize(scale as usize).unwrap();
+        let sign = if mantissa < 0 { DecimalSign::Negative } else { DecimalSign::Positive };
+        Decimal::from_coefficient(sign, scale, mantissa.unsigned_abs())
+    }
+    /// Signed unscaled value, always within +-(10^38 - 1).
+    pub fn mantissa(&self) -> i128 {
+        let coefficient = self.coefficient() as i128;
+        if self.sign == DecimalSign::Negative { -coefficient } else { coefficient }
+    }
+    pub fn scale(&self) -> u8 {
+        self.scale as u8
+    }
+    pub(crate) fn coefficient(&self) -> u128 {
+        (self.part_3 as u128) << 96 | (self.part_2 as u128) << 64 | (self.part_1 as u128) << 32 | self.part_0 as u128
+    }
+    pub(crate) fn from_coefficient(sign: DecimalSign, scale: DecimalScale, coefficient: u128) -> Result<Decimal, DecimalError> {
+        if coefficient > DECIMAL_COEFFICIENT_MAX {
+            return Err(DecimalError); // Overflow
+        }
+        let digits = coefficient.checked_ilog10().map_or(1, |log| log as usize + 1);
+        let precision = match DecimalPrecision::try_from_usize(digits.max(scale as usize).max(1)) {
+            Ok(value) => value,
+            Err(_) => return Err(DecimalError),
+        };
+        Ok(Decimal::new(
+            sign,
+            precision,
+            scale,
+            coefficient as u32,
+            (coefficient >> 32) as u32,
+            (coefficient >> 64) as u32,
+            (coefficient >> 96) as u32,
+        ))
+    }
+    pub fn rescale(&self, scale: DecimalScale, mode: RoundingMode) -> Result<Decimal, DecimalError> {
+        let negative = self.sign == DecimalSign::Negative;
+        let coefficient = if scale as usize >= self.scale as usize {
+            match 10u128.checked_pow(scale as u32 - self.scale as u32).and_then(|factor| self.coefficient().checked_mul(factor)) {
+                Some(value) => value,
+                None => return Err(DecimalError), // Overflow
+            }
+        } else {
+            mode.round(self.coefficient(), self.scale as u32 - scale as u32, negative)?
+        };
+        Decimal::from_coefficient(self.sign, scale, coefficient)
+    }
     pub fn normalize(&self, scale: DecimalScale) -> Decimal { // See add, sub
         Decimal::new(
             self.sign,
@@ -0,0 +1,235 @@
+use crate::{Decimal, DecimalError, DecimalSign};
+
+/// Separators and grouping used to format and parse a `Decimal` for a locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecimalLocale {
+    pub decimal_separator: char,
+    pub group_separator: Option<char>,
+    pub primary_grouping: u8,   // Size of the group next to the decimal separator
+    pub secondary_grouping: u8, // Size of every further group (3 for 1,234,567; 2 for 12,34,567)
+    pub minus_sign: char,
+    pub currency_position: CurrencyPosition,
+}
+
+/// Where a currency symbol goes relative to the number: `$1.00`, `CHF 1.00` or `1,00 €`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurrencyPosition {
+    Before,
+    BeforeWithSpace,
+    After,
+    AfterWithSpace,
+}
+
+pub const DECIMAL_LOCALE_EN_US: DecimalLocale = DecimalLocale {
+    decimal_separator: '.',
+    group_separator: Some(','),
+    primary_grouping: 3,
+    secondary_grouping: 3,
+    minus_sign: '-',
+    currency_position: CurrencyPosition::Before,
+};
+
+pub const DECIMAL_LOCALE_RU_RU: DecimalLocale = DecimalLocale {
+    decimal_separator: ',',
+    group_separator: Some('\u{00A0}'), // No-break space
+    primary_grouping: 3,
+    secondary_grouping: 3,
+    minus_sign: '-',
+    currency_position: CurrencyPosition::AfterWithSpace,
+};
+
+pub const DECIMAL_LOCALE_DE_DE: DecimalLocale = DecimalLocale {
+    decimal_separator: ',',
+    group_separator: Some('.'),
+    primary_grouping: 3,
+    secondary_grouping: 3,
+    minus_sign: '-',
+    currency_position: CurrencyPosition::AfterWithSpace,
+};
+
+pub const DECIMAL_LOCALE_FR_FR: DecimalLocale = DecimalLocale {
+    decimal_separator: ',',
+    group_separator: Some('\u{202F}'), // Narrow no-break space
+    primary_grouping: 3,
+    secondary_grouping: 3,
+    minus_sign: '-',
+    currency_position: CurrencyPosition::AfterWithSpace,
+};
+
+pub const DECIMAL_LOCALE_DE_CH: DecimalLocale = DecimalLocale {
+    decimal_separator: '.',
+    group_separator: Some('\u{2019}'), // Right single quotation mark
+    primary_grouping: 3,
+    secondary_grouping: 3,
+    minus_sign: '-',
+    currency_position: CurrencyPosition::BeforeWithSpace,
+};
+
+impl DecimalLocale {
+    /// Custom locale with groups of three digits, an ASCII minus sign and the currency symbol in front.
+    pub fn new(decimal_separator: char, group_separator: Option<char>) -> DecimalLocale {
+        DecimalLocale {
+            decimal_separator,
+            group_separator,
+            primary_grouping: 3,
+            secondary_grouping: 3,
+            minus_sign: '-',
+            currency_position: CurrencyPosition::Before,
+        }
+    }
+    /// Preset for a BCP 47 tag such as `ru-RU` (`ru_RU` is accepted as well).
+    pub fn from_tag(tag: &str) -> Result<DecimalLocale, DecimalError> {
+        match tag.replace('_', "-").to_ascii_lowercase().as_str() {
+            "en-us" => Ok(DECIMAL_LOCALE_EN_US),
+            "ru-ru" => Ok(DECIMAL_LOCALE_RU_RU),
+            "de-de" => Ok(DECIMAL_LOCALE_DE_DE),
+            "fr-fr" => Ok(DECIMAL_LOCALE_FR_FR),
+            "de-ch" => Ok(DECIMAL_LOCALE_DE_CH),
+            _ => Err(DecimalError), // Unknown locale
+        }
+    }
+    pub(crate) fn group_digits(&self, digits: &str) -> String {
+        let group_separator = match self.group_separator {
+            Some(value) if self.primary_grouping > 0 => value,
+            _ => return String::from(digits),
+        };
+        let secondary_grouping = if self.secondary_grouping == 0 { self.primary_grouping } else { self.secondary_grouping };
+        let mut groups: Vec<&str> = Vec::new();
+        let mut end = digits.len();
+        let mut size = self.primary_grouping as usize;
+        while end > size {
+            groups.push(&digits[end - size..end]);
+            end -= size;
+            size = secondary_grouping as usize;
+        }
+        groups.push(&digits[0..end]);
+        let mut result = String::new();
+        for (index, group) in groups.iter().rev().enumerate() {
+            if index > 0 {
+                result.push(group_separator);
+            }
+            result.push_str(group);
+        }
+        result
+    }
+    fn is_group_separator(&self, char: char) -> bool {
+        match self.group_separator {
+            Some(value) if value.is_whitespace() => char == ' ' || char == '\u{00A0}' || char == '\u{202F}',
+            Some(value) => char == value,
+            None => false,
+        }
+    }
+    fn is_minus_sign(&self, char: char) -> bool {
+        char == self.minus_sign || char == '-'
+    }
+}
+
+impl Decimal {
+    /// Formats the value with the separators of `locale`, keeping its scale.
+    pub fn format_locale(&self, locale: &DecimalLocale) -> String {
+        let digits = format!("{:0>width$}", self.coefficient(), width = self.scale as usize + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - self.scale as usize);
+        let mut result = String::new();
+        if self.sign == DecimalSign::Negative && self.coefficient() != 0 {
+            result.push(locale.minus_sign);
+        }
+        result.push_str(&locale.group_digits(integer));
+        if !fraction.is_empty() {
+            result.push(locale.decimal_separator);
+            result.push_str(fraction);
+        }
+        result
+    }
+    /// Parses text written with the separators of `locale`, e.g. `1 234 567,89` for `ru-RU`.
+    pub fn parse_locale(text: &str, locale: &DecimalLocale) -> Result<Decimal, DecimalError> {
+        let text = text.trim();
+        let (negative, unsigned) = match text.chars().next() {
+            Some(char) if locale.is_minus_sign(char) => (true, &text[char.len_utf8()..]),
+            Some('+') => (false, &text[1..]),
+            Some(_) => (false, text),
+            None => return Err(DecimalError),
+        };
+        if unsigned.chars().any(|char| char == '+' || locale.is_minus_sign(char)) {
+            return Err(DecimalError); // Second sign, Decimal::parse would accept it
+        }
+        let (integer, fraction) = match unsigned.find(locale.decimal_separator) {
+            Some(index) => (&unsigned[..index], Some(&unsigned[index + locale.decimal_separator.len_utf8()..])),
+            None => (unsigned, None),
+        };
+        let groups: Vec<&str> = integer.split(|char| locale.is_group_separator(char)).collect();
+        if groups.len() > 1 {
+            // Every group must match the locale grouping, only the leftmost one may be shorter
+            let secondary_grouping = if locale.secondary_grouping == 0 { locale.primary_grouping } else { locale.secondary_grouping };
+            for (index, group) in groups.iter().rev().enumerate() {
+                let size = if index == 0 { locale.primary_grouping } else { secondary_grouping } as usize;
+                let valid = if index == groups.len() - 1 { !group.is_empty() && group.len() <= size } else { group.len() == size };
+                if !valid {
+                    return Err(DecimalError);
+                }
+            }
+        }
+        let digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        if !groups.iter().all(|group| digits(group)) || fraction.is_some_and(|fraction| !digits(fraction)) {
+            return Err(DecimalError); // Only the separators of the locale may appear between digits
+        }
+        let mut normalized = String::new();
+        if negative {
+            normalized.push('-');
+        }
+        for group in groups {
+            normalized.push_str(group);
+        }
+        if let Some(fraction) = fraction {
+            if fraction.is_empty() {
+                return Err(DecimalError);
+            }
+            normalized.push('.');
+            normalized.push_str(fraction);
+        }
+        if !normalized.chars().any(|char| char.is_ascii_digit()) {
+            return Err(DecimalError);
+        }
+        Decimal::parse(&normalized)
+    }
+}
+
+#[test]
+fn decimal_format_locale_works() {
+    let decimal = Decimal::parse("-1234567.89").unwrap();
+    assert_eq!(decimal.format_locale(&DECIMAL_LOCALE_EN_US), "-1,234,567.89");
+    assert_eq!(decimal.format_locale(&DECIMAL_LOCALE_RU_RU), "-1\u{00A0}234\u{00A0}567,89");
+    assert_eq!(decimal.format_locale(&DECIMAL_LOCALE_DE_DE), "-1.234.567,89");
+    assert_eq!(decimal.format_locale(&DECIMAL_LOCALE_FR_FR), "-1\u{202F}234\u{202F}567,89");
+    assert_eq!(decimal.format_locale(&DECIMAL_LOCALE_DE_CH), "-1\u{2019}234\u{2019}567.89");
+
+    let indian = DecimalLocale { secondary_grouping: 2, ..DECIMAL_LOCALE_EN_US };
+    assert_eq!(Decimal::parse("1234567").unwrap().format_locale(&indian), "12,34,567");
+    assert_eq!(Decimal::parse("0.05").unwrap().format_locale(&DECIMAL_LOCALE_DE_DE), "0,05");
+    assert_eq!(Decimal::parse("999").unwrap().format_locale(&DECIMAL_LOCALE_EN_US), "999");
+}
+
+#[test]
+fn decimal_parse_locale_works() {
+    let ru = Decimal::parse_locale("1 234 567,89", &DECIMAL_LOCALE_RU_RU).unwrap();
+    let en = Decimal::parse_locale("1,234,567.89", &DecimalLocale::from_tag("en-US").unwrap()).unwrap();
+    assert_eq!(ru.to_parts(), en.to_parts());
+    assert_eq!(ru.format_locale(&DECIMAL_LOCALE_EN_US), "1,234,567.89");
+    assert_eq!(en.format_locale(&DECIMAL_LOCALE_RU_RU), "1\u{00A0}234\u{00A0}567,89");
+
+    let custom = DecimalLocale { minus_sign: '\u{2212}', ..DecimalLocale::new(',', Some('_')) };
+    let decimal = Decimal::parse_locale("\u{2212}12_345,6", &custom).unwrap();
+    assert_eq!(decimal.format_locale(&custom), "\u{2212}12_345,6");
+    assert_eq!(Decimal::parse_locale("1234567,89", &DECIMAL_LOCALE_DE_DE).unwrap().to_parts(), ru.to_parts());
+
+    assert!(Decimal::parse_locale("1,23,456.00", &DECIMAL_LOCALE_EN_US).is_err());
+    assert!(Decimal::parse_locale("1.234,5", &DECIMAL_LOCALE_EN_US).is_err());
+    assert!(Decimal::parse_locale("-", &DECIMAL_LOCALE_EN_US).is_err());
+    assert!(Decimal::parse_locale("+-1", &DECIMAL_LOCALE_EN_US).is_err());
+    assert!(Decimal::parse_locale("1.5", &DECIMAL_LOCALE_RU_RU).is_err()); // ru-RU writes 1,5
+    assert!(Decimal::parse_locale("1,5.5", &DECIMAL_LOCALE_RU_RU).is_err());
+    assert!(Decimal::parse_locale("12a", &DECIMAL_LOCALE_EN_US).is_err());
+    assert!(Decimal::parse_locale("--1", &DECIMAL_LOCALE_EN_US).is_err());
+    assert!(Decimal::parse_locale("\u{2212}-1", &custom).is_err());
+    assert_eq!(Decimal::parse_locale("+1", &DECIMAL_LOCALE_EN_US).unwrap().mantissa(), 1);
+    assert!(DecimalLocale::from_tag("xx-XX").is_err());
+}
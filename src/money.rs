@@ -0,0 +1,331 @@
+use crate::{CurrencyPosition, Decimal, DecimalError, DecimalLocale, DecimalScale, DecimalSign, RoundingMode};
+
+/// ISO 4217 currency: alphabetic code, numeric code, minor units (decimal places) and symbol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Currency {
+    pub code: &'static str,
+    pub numeric: u16,
+    pub minor_units: u8,
+    pub symbol: &'static str,
+}
+
+pub const CURRENCIES: &[Currency] = &[
+    Currency { code: "AED", numeric: 784, minor_units: 2, symbol: "د.إ" },
+    Currency { code: "AFN", numeric: 971, minor_units: 2, symbol: "؋" },
+    Currency { code: "ALL", numeric: 8, minor_units: 2, symbol: "L" },
+    Currency { code: "AMD", numeric: 51, minor_units: 2, symbol: "֏" },
+    Currency { code: "ANG", numeric: 532, minor_units: 2, symbol: "ƒ" },
+    Currency { code: "AOA", numeric: 973, minor_units: 2, symbol: "Kz" },
+    Currency { code: "ARS", numeric: 32, minor_units: 2, symbol: "$" },
+    Currency { code: "AUD", numeric: 36, minor_units: 2, symbol: "A$" },
+    Currency { code: "AWG", numeric: 533, minor_units: 2, symbol: "ƒ" },
+    Currency { code: "AZN", numeric: 944, minor_units: 2, symbol: "₼" },
+    Currency { code: "BAM", numeric: 977, minor_units: 2, symbol: "KM" },
+    Currency { code: "BBD", numeric: 52, minor_units: 2, symbol: "$" },
+    Currency { code: "BDT", numeric: 50, minor_units: 2, symbol: "৳" },
+    Currency { code: "BGN", numeric: 975, minor_units: 2, symbol: "лв" },
+    Currency { code: "BHD", numeric: 48, minor_units: 3, symbol: "BHD" },
+    Currency { code: "BIF", numeric: 108, minor_units: 0, symbol: "FBu" },
+    Currency { code: "BMD", numeric: 60, minor_units: 2, symbol: "$" },
+    Currency { code: "BND", numeric: 96, minor_units: 2, symbol: "$" },
+    Currency { code: "BOB", numeric: 68, minor_units: 2, symbol: "Bs" },
+    Currency { code: "BOV", numeric: 984, minor_units: 2, symbol: "BOV" },
+    Currency { code: "BRL", numeric: 986, minor_units: 2, symbol: "R$" },
+    Currency { code: "BSD", numeric: 44, minor_units: 2, symbol: "$" },
+    Currency { code: "BTN", numeric: 64, minor_units: 2, symbol: "Nu." },
+    Currency { code: "BWP", numeric: 72, minor_units: 2, symbol: "P" },
+    Currency { code: "BYN", numeric: 933, minor_units: 2, symbol: "Br" },
+    Currency { code: "BZD", numeric: 84, minor_units: 2, symbol: "$" },
+    Currency { code: "CAD", numeric: 124, minor_units: 2, symbol: "CA$" },
+    Currency { code: "CDF", numeric: 976, minor_units: 2, symbol: "FC" },
+    Currency { code: "CHE", numeric: 947, minor_units: 2, symbol: "CHE" },
+    Currency { code: "CHF", numeric: 756, minor_units: 2, symbol: "CHF" },
+    Currency { code: "CHW", numeric: 948, minor_units: 2, symbol: "CHW" },
+    Currency { code: "CLF", numeric: 990, minor_units: 4, symbol: "UF" },
+    Currency { code: "CLP", numeric: 152, minor_units: 0, symbol: "$" },
+    Currency { code: "CNY", numeric: 156, minor_units: 2, symbol: "¥" },
+    Currency { code: "COP", numeric: 170, minor_units: 2, symbol: "$" },
+    Currency { code: "COU", numeric: 970, minor_units: 2, symbol: "COU" },
+    Currency { code: "CRC", numeric: 188, minor_units: 2, symbol: "₡" },
+    Currency { code: "CUP", numeric: 192, minor_units: 2, symbol: "$" },
+    Currency { code: "CVE", numeric: 132, minor_units: 2, symbol: "$" },
+    Currency { code: "CZK", numeric: 203, minor_units: 2, symbol: "Kč" },
+    Currency { code: "DJF", numeric: 262, minor_units: 0, symbol: "Fdj" },
+    Currency { code: "DKK", numeric: 208, minor_units: 2, symbol: "kr" },
+    Currency { code: "DOP", numeric: 214, minor_units: 2, symbol: "$" },
+    Currency { code: "DZD", numeric: 12, minor_units: 2, symbol: "DA" },
+    Currency { code: "EGP", numeric: 818, minor_units: 2, symbol: "E£" },
+    Currency { code: "ERN", numeric: 232, minor_units: 2, symbol: "Nfk" },
+    Currency { code: "ETB", numeric: 230, minor_units: 2, symbol: "Br" },
+    Currency { code: "EUR", numeric: 978, minor_units: 2, symbol: "€" },
+    Currency { code: "FJD", numeric: 242, minor_units: 2, symbol: "$" },
+    Currency { code: "FKP", numeric: 238, minor_units: 2, symbol: "£" },
+    Currency { code: "GBP", numeric: 826, minor_units: 2, symbol: "£" },
+    Currency { code: "GEL", numeric: 981, minor_units: 2, symbol: "₾" },
+    Currency { code: "GHS", numeric: 936, minor_units: 2, symbol: "GH₵" },
+    Currency { code: "GIP", numeric: 292, minor_units: 2, symbol: "£" },
+    Currency { code: "GMD", numeric: 270, minor_units: 2, symbol: "D" },
+    Currency { code: "GNF", numeric: 324, minor_units: 0, symbol: "FG" },
+    Currency { code: "GTQ", numeric: 320, minor_units: 2, symbol: "Q" },
+    Currency { code: "GYD", numeric: 328, minor_units: 2, symbol: "$" },
+    Currency { code: "HKD", numeric: 344, minor_units: 2, symbol: "HK$" },
+    Currency { code: "HNL", numeric: 340, minor_units: 2, symbol: "L" },
+    Currency { code: "HTG", numeric: 332, minor_units: 2, symbol: "G" },
+    Currency { code: "HUF", numeric: 348, minor_units: 2, symbol: "Ft" },
+    Currency { code: "IDR", numeric: 360, minor_units: 2, symbol: "Rp" },
+    Currency { code: "ILS", numeric: 376, minor_units: 2, symbol: "₪" },
+    Currency { code: "INR", numeric: 356, minor_units: 2, symbol: "₹" },
+    Currency { code: "IQD", numeric: 368, minor_units: 3, symbol: "IQD" },
+    Currency { code: "IRR", numeric: 364, minor_units: 2, symbol: "IRR" },
+    Currency { code: "ISK", numeric: 352, minor_units: 0, symbol: "kr" },
+    Currency { code: "JMD", numeric: 388, minor_units: 2, symbol: "$" },
+    Currency { code: "JOD", numeric: 400, minor_units: 3, symbol: "JOD" },
+    Currency { code: "JPY", numeric: 392, minor_units: 0, symbol: "¥" },
+    Currency { code: "KES", numeric: 404, minor_units: 2, symbol: "KSh" },
+    Currency { code: "KGS", numeric: 417, minor_units: 2, symbol: "сом" },
+    Currency { code: "KHR", numeric: 116, minor_units: 2, symbol: "៛" },
+    Currency { code: "KMF", numeric: 174, minor_units: 0, symbol: "CF" },
+    Currency { code: "KPW", numeric: 408, minor_units: 2, symbol: "₩" },
+    Currency { code: "KRW", numeric: 410, minor_units: 0, symbol: "₩" },
+    Currency { code: "KWD", numeric: 414, minor_units: 3, symbol: "KWD" },
+    Currency { code: "KYD", numeric: 136, minor_units: 2, symbol: "$" },
+    Currency { code: "KZT", numeric: 398, minor_units: 2, symbol: "₸" },
+    Currency { code: "LAK", numeric: 418, minor_units: 2, symbol: "₭" },
+    Currency { code: "LBP", numeric: 422, minor_units: 2, symbol: "L£" },
+    Currency { code: "LKR", numeric: 144, minor_units: 2, symbol: "Rs" },
+    Currency { code: "LRD", numeric: 430, minor_units: 2, symbol: "$" },
+    Currency { code: "LSL", numeric: 426, minor_units: 2, symbol: "L" },
+    Currency { code: "LYD", numeric: 434, minor_units: 3, symbol: "LD" },
+    Currency { code: "MAD", numeric: 504, minor_units: 2, symbol: "MAD" },
+    Currency { code: "MDL", numeric: 498, minor_units: 2, symbol: "L" },
+    Currency { code: "MGA", numeric: 969, minor_units: 2, symbol: "Ar" },
+    Currency { code: "MKD", numeric: 807, minor_units: 2, symbol: "ден" },
+    Currency { code: "MMK", numeric: 104, minor_units: 2, symbol: "K" },
+    Currency { code: "MNT", numeric: 496, minor_units: 2, symbol: "₮" },
+    Currency { code: "MOP", numeric: 446, minor_units: 2, symbol: "MOP$" },
+    Currency { code: "MRU", numeric: 929, minor_units: 2, symbol: "UM" },
+    Currency { code: "MUR", numeric: 480, minor_units: 2, symbol: "Rs" },
+    Currency { code: "MVR", numeric: 462, minor_units: 2, symbol: "Rf" },
+    Currency { code: "MWK", numeric: 454, minor_units: 2, symbol: "MK" },
+    Currency { code: "MXN", numeric: 484, minor_units: 2, symbol: "MX$" },
+    Currency { code: "MXV", numeric: 979, minor_units: 2, symbol: "MXV" },
+    Currency { code: "MYR", numeric: 458, minor_units: 2, symbol: "RM" },
+    Currency { code: "MZN", numeric: 943, minor_units: 2, symbol: "MT" },
+    Currency { code: "NAD", numeric: 516, minor_units: 2, symbol: "$" },
+    Currency { code: "NGN", numeric: 566, minor_units: 2, symbol: "₦" },
+    Currency { code: "NIO", numeric: 558, minor_units: 2, symbol: "C$" },
+    Currency { code: "NOK", numeric: 578, minor_units: 2, symbol: "kr" },
+    Currency { code: "NPR", numeric: 524, minor_units: 2, symbol: "Rs" },
+    Currency { code: "NZD", numeric: 554, minor_units: 2, symbol: "NZ$" },
+    Currency { code: "OMR", numeric: 512, minor_units: 3, symbol: "OMR" },
+    Currency { code: "PAB", numeric: 590, minor_units: 2, symbol: "B/." },
+    Currency { code: "PEN", numeric: 604, minor_units: 2, symbol: "S/" },
+    Currency { code: "PGK", numeric: 598, minor_units: 2, symbol: "K" },
+    Currency { code: "PHP", numeric: 608, minor_units: 2, symbol: "₱" },
+    Currency { code: "PKR", numeric: 586, minor_units: 2, symbol: "Rs" },
+    Currency { code: "PLN", numeric: 985, minor_units: 2, symbol: "zł" },
+    Currency { code: "PYG", numeric: 600, minor_units: 0, symbol: "₲" },
+    Currency { code: "QAR", numeric: 634, minor_units: 2, symbol: "QAR" },
+    Currency { code: "RON", numeric: 946, minor_units: 2, symbol: "lei" },
+    Currency { code: "RSD", numeric: 941, minor_units: 2, symbol: "дин." },
+    Currency { code: "RUB", numeric: 643, minor_units: 2, symbol: "₽" },
+    Currency { code: "RWF", numeric: 646, minor_units: 0, symbol: "RF" },
+    Currency { code: "SAR", numeric: 682, minor_units: 2, symbol: "SAR" },
+    Currency { code: "SBD", numeric: 90, minor_units: 2, symbol: "$" },
+    Currency { code: "SCR", numeric: 690, minor_units: 2, symbol: "Rs" },
+    Currency { code: "SDG", numeric: 938, minor_units: 2, symbol: "SDG" },
+    Currency { code: "SEK", numeric: 752, minor_units: 2, symbol: "kr" },
+    Currency { code: "SGD", numeric: 702, minor_units: 2, symbol: "S$" },
+    Currency { code: "SHP", numeric: 654, minor_units: 2, symbol: "£" },
+    Currency { code: "SLE", numeric: 925, minor_units: 2, symbol: "Le" },
+    Currency { code: "SOS", numeric: 706, minor_units: 2, symbol: "Sh" },
+    Currency { code: "SRD", numeric: 968, minor_units: 2, symbol: "$" },
+    Currency { code: "SSP", numeric: 728, minor_units: 2, symbol: "£" },
+    Currency { code: "STN", numeric: 930, minor_units: 2, symbol: "Db" },
+    Currency { code: "SVC", numeric: 222, minor_units: 2, symbol: "₡" },
+    Currency { code: "SYP", numeric: 760, minor_units: 2, symbol: "£" },
+    Currency { code: "SZL", numeric: 748, minor_units: 2, symbol: "L" },
+    Currency { code: "THB", numeric: 764, minor_units: 2, symbol: "฿" },
+    Currency { code: "TJS", numeric: 972, minor_units: 2, symbol: "SM" },
+    Currency { code: "TMT", numeric: 934, minor_units: 2, symbol: "m" },
+    Currency { code: "TND", numeric: 788, minor_units: 3, symbol: "DT" },
+    Currency { code: "TOP", numeric: 776, minor_units: 2, symbol: "T$" },
+    Currency { code: "TRY", numeric: 949, minor_units: 2, symbol: "₺" },
+    Currency { code: "TTD", numeric: 780, minor_units: 2, symbol: "$" },
+    Currency { code: "TWD", numeric: 901, minor_units: 2, symbol: "NT$" },
+    Currency { code: "TZS", numeric: 834, minor_units: 2, symbol: "TSh" },
+    Currency { code: "UAH", numeric: 980, minor_units: 2, symbol: "₴" },
+    Currency { code: "UGX", numeric: 800, minor_units: 0, symbol: "USh" },
+    Currency { code: "USD", numeric: 840, minor_units: 2, symbol: "$" },
+    Currency { code: "USN", numeric: 997, minor_units: 2, symbol: "USN" },
+    Currency { code: "UYI", numeric: 940, minor_units: 0, symbol: "UYI" },
+    Currency { code: "UYU", numeric: 858, minor_units: 2, symbol: "$" },
+    Currency { code: "UYW", numeric: 927, minor_units: 4, symbol: "UYW" },
+    Currency { code: "UZS", numeric: 860, minor_units: 2, symbol: "soʻm" },
+    Currency { code: "VED", numeric: 926, minor_units: 2, symbol: "Bs.D" },
+    Currency { code: "VES", numeric: 928, minor_units: 2, symbol: "Bs.S" },
+    Currency { code: "VND", numeric: 704, minor_units: 0, symbol: "₫" },
+    Currency { code: "VUV", numeric: 548, minor_units: 0, symbol: "VT" },
+    Currency { code: "WST", numeric: 882, minor_units: 2, symbol: "WS$" },
+    Currency { code: "XAF", numeric: 950, minor_units: 0, symbol: "FCFA" },
+    Currency { code: "XCD", numeric: 951, minor_units: 2, symbol: "EC$" },
+    Currency { code: "XOF", numeric: 952, minor_units: 0, symbol: "F CFA" },
+    Currency { code: "XPF", numeric: 953, minor_units: 0, symbol: "CFPF" },
+    Currency { code: "YER", numeric: 886, minor_units: 2, symbol: "YER" },
+    Currency { code: "ZAR", numeric: 710, minor_units: 2, symbol: "R" },
+    Currency { code: "ZMW", numeric: 967, minor_units: 2, symbol: "K" },
+    Currency { code: "ZWG", numeric: 924, minor_units: 2, symbol: "ZWG" },
+];
+
+impl Currency {
+    pub fn from_code(code: &str) -> Result<Currency, DecimalError> {
+        match CURRENCIES.iter().find(|currency| currency.code.eq_ignore_ascii_case(code)) {
+            Some(currency) => Ok(*currency),
+            None => Err(DecimalError), // Unknown currency
+        }
+    }
+    pub fn from_numeric(numeric: u16) -> Result<Currency, DecimalError> {
+        match CURRENCIES.iter().find(|currency| currency.numeric == numeric) {
+            Some(currency) => Ok(*currency),
+            None => Err(DecimalError), // Unknown currency
+        }
+    }
+    pub fn scale(&self) -> DecimalScale {
+        DecimalScale::try_from_usize(self.minor_units as usize).unwrap()
+    }
+}
+
+/// Amount in a currency, kept at the currency's minor units (JPY 0, USD 2, KWD 3). A zero amount has
+/// scale 0 like every zero `Decimal`, formatting still shows the minor units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    /// Rounds `amount` to the minor units of `currency`.
+    pub fn new(amount: &Decimal, currency: Currency, mode: RoundingMode) -> Result<Money, DecimalError> {
+        Ok(Money {
+            amount: amount.rescale(currency.scale(), mode)?,
+            currency,
+        })
+    }
+    /// Amount given in minor units, e.g. cents for USD.
+    pub fn from_minor_units(units: i64, currency: Currency) -> Result<Money, DecimalError> {
+        let sign = if units < 0 { DecimalSign::Negative } else { DecimalSign::Positive };
+        Ok(Money {
+            amount: Decimal::from_coefficient(sign, currency.scale(), units.unsigned_abs() as u128)?,
+            currency,
+        })
+    }
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+    /// Fails with `DecimalError` when the currencies differ or on overflow.
+    pub fn add(&self, rhs: &Money) -> Result<Money, DecimalError> {
+        self.add_signed(rhs, false)
+    }
+    /// Fails with `DecimalError` when the currencies differ or on overflow.
+    pub fn sub(&self, rhs: &Money) -> Result<Money, DecimalError> {
+        self.add_signed(rhs, true)
+    }
+    fn add_signed(&self, rhs: &Money, negate: bool) -> Result<Money, DecimalError> {
+        if self.currency != rhs.currency {
+            return Err(DecimalError); // Currency mismatch
+        }
+        let signed = |value: &Decimal, negate: bool| {
+            let coefficient = value.coefficient() as i128; // Below 10^38, fits into i128
+            if (value.sign == DecimalSign::Negative) != negate { -coefficient } else { coefficient }
+        };
+        let result = match signed(&self.amount, false).checked_add(signed(&rhs.amount, negate)) {
+            Some(value) => value,
+            None => return Err(DecimalError), // Overflow
+        };
+        let sign = if result < 0 { DecimalSign::Negative } else { DecimalSign::Positive };
+        Ok(Money {
+            amount: Decimal::from_coefficient(sign, self.currency.scale(), result.unsigned_abs())?,
+            currency: self.currency,
+        })
+    }
+    /// Formats the amount for `locale` and puts the currency symbol where the locale expects it.
+    pub fn format_locale(&self, locale: &DecimalLocale) -> String {
+        // Zero is stored at scale 0, pad it to the minor units
+        let number = Decimal { sign: DecimalSign::Positive, scale: self.currency.scale(), ..self.amount }.format_locale(locale);
+        let mut result = String::new();
+        if self.amount.sign == DecimalSign::Negative && self.amount.coefficient() != 0 {
+            result.push(locale.minus_sign);
+        }
+        // An ISO code used as the symbol needs a space where a glyph such as $ does not: KWD 1.500
+        let position = match (locale.currency_position, self.currency.symbol == self.currency.code) {
+            (CurrencyPosition::Before, true) => CurrencyPosition::BeforeWithSpace,
+            (CurrencyPosition::After, true) => CurrencyPosition::AfterWithSpace,
+            (position, _) => position,
+        };
+        match position {
+            CurrencyPosition::Before => {
+                result.push_str(self.currency.symbol);
+                result.push_str(&number);
+            },
+            CurrencyPosition::BeforeWithSpace => {
+                result.push_str(self.currency.symbol);
+                result.push('\u{00A0}');
+                result.push_str(&number);
+            },
+            CurrencyPosition::After => {
+                result.push_str(&number);
+                result.push_str(self.currency.symbol);
+            },
+            CurrencyPosition::AfterWithSpace => {
+                result.push_str(&number);
+                result.push('\u{00A0}');
+                result.push_str(self.currency.symbol);
+            },
+        }
+        result
+    }
+}
+
+#[test]
+fn money_works() {
+    let usd = Currency::from_code("USD").unwrap();
+    let jpy = Currency::from_code("jpy").unwrap();
+    let kwd = Currency::from_numeric(414).unwrap();
+    assert_eq!((jpy.minor_units, kwd.code, kwd.minor_units), (0, "KWD", 3));
+
+    let price = Decimal::parse("1234.565").unwrap();
+    assert_eq!(Money::new(&price, usd, RoundingMode::HalfEven).unwrap().amount().to_string(), "1234.56");
+    assert_eq!(Money::new(&price, usd, RoundingMode::HalfUp).unwrap().amount().to_string(), "1234.57");
+    assert_eq!(Money::new(&price, jpy, RoundingMode::HalfUp).unwrap().amount().to_string(), "1235.0");
+    assert_eq!(Money::new(&price, kwd, RoundingMode::Unnecessary).unwrap().amount().to_string(), "1234.565");
+    assert!(Money::new(&price, usd, RoundingMode::Unnecessary).is_err());
+
+    let a = Money::from_minor_units(1050, usd).unwrap();
+    let b = Money::from_minor_units(-2075, usd).unwrap();
+    assert_eq!(a.add(&b).unwrap().amount().to_string(), "-10.25");
+    assert_eq!(a.sub(&b).unwrap().amount().to_string(), "31.25");
+    assert!(a.add(&Money::from_minor_units(1, jpy).unwrap()).is_err());
+
+    let money = Money::new(&Decimal::parse("-1234567.8").unwrap(), Currency::from_code("EUR").unwrap(), RoundingMode::HalfEven).unwrap();
+    assert_eq!(money.format_locale(&crate::DECIMAL_LOCALE_DE_DE), "-1.234.567,80\u{00A0}€");
+    assert_eq!(b.format_locale(&crate::DECIMAL_LOCALE_EN_US), "-$20.75");
+    let rub = Money::from_minor_units(123456789, Currency::from_code("RUB").unwrap()).unwrap();
+    assert_eq!(rub.format_locale(&crate::DECIMAL_LOCALE_RU_RU), "1\u{00A0}234\u{00A0}567,89\u{00A0}₽");
+    let chf = Money::from_minor_units(100000, Currency::from_code("CHF").unwrap()).unwrap();
+    assert_eq!(chf.format_locale(&crate::DECIMAL_LOCALE_DE_CH), "CHF\u{00A0}1\u{2019}000.00");
+
+    let zero = Money::from_minor_units(0, usd).unwrap();
+    assert_eq!(zero.format_locale(&crate::DECIMAL_LOCALE_EN_US), "$0.00");
+    assert_eq!(a.sub(&a).unwrap().format_locale(&crate::DECIMAL_LOCALE_EN_US), "$0.00");
+    let zero = Money::new(&Decimal::parse("-0.0004").unwrap(), kwd, RoundingMode::HalfEven).unwrap();
+    assert_eq!(zero.format_locale(&crate::DECIMAL_LOCALE_EN_US), "KWD\u{00A0}0.000");
+    let dinar = Money::from_minor_units(1500, kwd).unwrap();
+    assert_eq!(dinar.format_locale(&crate::DECIMAL_LOCALE_EN_US), "KWD\u{00A0}1.500");
+    let after = DecimalLocale { currency_position: CurrencyPosition::After, ..crate::DECIMAL_LOCALE_EN_US };
+    assert_eq!(dinar.format_locale(&after), "1.500\u{00A0}KWD");
+    assert_eq!(b.format_locale(&after), "-20.75$");
+    assert_eq!(Money::from_minor_units(0, jpy).unwrap().format_locale(&crate::DECIMAL_LOCALE_EN_US), "¥0");
+}
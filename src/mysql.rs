@@ -0,0 +1,109 @@
+use crate::{Decimal, DecimalError, DecimalPrecision, DecimalScale, DecimalSign, RoundingMode};
+
+// Bytes taken by 0..9 leftover digits, a full group of nine digits takes four
+const MYSQL_DIGIT_BYTES: [usize; 10] = [0, 1, 1, 2, 2, 3, 3, 4, 4, 4];
+
+// Digit counts of the groups of a DECIMAL(M,D) value, most significant first
+fn mysql_groups(precision: DecimalPrecision, scale: DecimalScale) -> Vec<usize> {
+    let (integer, fraction) = (precision as usize - scale as usize, scale as usize);
+    let mut groups = Vec::new();
+    if integer % 9 > 0 {
+        groups.push(integer % 9);
+    }
+    groups.extend(std::iter::repeat_n(9, integer / 9 + fraction / 9));
+    if fraction % 9 > 0 {
+        groups.push(fraction % 9);
+    }
+    groups
+}
+
+impl Decimal {
+    /// MySQL binary DECIMAL(M,D) storage as in binlog rows: big-endian groups of nine digits in four bytes
+    /// on each side of the point, leftover digits in 1 to 4 bytes, all bytes inverted for negatives
+    /// and the top bit of the first byte flipped. The value must fit the column exactly.
+    pub fn to_mysql_binary(&self, precision: DecimalPrecision, scale: DecimalScale) -> Result<Vec<u8>, DecimalError> {
+        if scale as usize > precision as usize {
+            return Err(DecimalError); // Scale above precision
+        }
+        let value = self.rescale(scale, RoundingMode::Unnecessary)?;
+        let digits = format!("{:0>width$}", value.coefficient(), width = precision as usize);
+        if digits.len() > precision as usize {
+            return Err(DecimalError); // Does not fit the column
+        }
+        let negative = value.sign == DecimalSign::Negative && value.coefficient() != 0;
+        let mut result = Vec::new();
+        let mut start = 0;
+        for size in mysql_groups(precision, scale) {
+            let group: u32 = digits[start..start + size].parse().unwrap();
+            let length = MYSQL_DIGIT_BYTES[size];
+            result.extend_from_slice(&group.to_be_bytes()[4 - length..]);
+            start += size;
+        }
+        if negative {
+            for byte in result.iter_mut() {
+                *byte = !*byte;
+            }
+        }
+        result[0] ^= 0x80;
+        Ok(result)
+    }
+    pub fn from_mysql_binary(bytes: &[u8], precision: DecimalPrecision, scale: DecimalScale) -> Result<Decimal, DecimalError> {
+        if scale as usize > precision as usize {
+            return Err(DecimalError); // Scale above precision
+        }
+        let groups = mysql_groups(precision, scale);
+        if bytes.len() != groups.iter().map(|size| MYSQL_DIGIT_BYTES[*size]).sum::<usize>() {
+            return Err(DecimalError); // Length does not match DECIMAL(M,D)
+        }
+        let negative = bytes[0] & 0x80 == 0;
+        let mut bytes = bytes.to_vec();
+        bytes[0] ^= 0x80;
+        if negative {
+            for byte in bytes.iter_mut() {
+                *byte = !*byte;
+            }
+        }
+        let mut coefficient = 0u128;
+        let mut start = 0;
+        for size in groups {
+            let length = MYSQL_DIGIT_BYTES[size];
+            let mut word = [0u8; 4];
+            word[4 - length..].copy_from_slice(&bytes[start..start + length]);
+            let group = u32::from_be_bytes(word);
+            if group >= 10u32.pow(size as u32) {
+                return Err(DecimalError); // Group above its digit count
+            }
+            coefficient = coefficient * 10u128.pow(size as u32) + group as u128;
+            start += length;
+        }
+        let sign = if negative { DecimalSign::Negative } else { DecimalSign::Positive };
+        Decimal::from_coefficient(sign, scale, coefficient)
+    }
+}
+
+#[test]
+fn decimal_mysql_binary_works() {
+    // DECIMAL(14,4), from the MySQL manual
+    let decimal = Decimal::parse("1234567890.1234").unwrap();
+    let bytes = decimal.to_mysql_binary(DecimalPrecision::Precision14, DecimalScale::Scale04).unwrap();
+    assert_eq!(bytes, vec![0x81, 0x0D, 0xFB, 0x38, 0xD2, 0x04, 0xD2]);
+    assert_eq!(Decimal::from_mysql_binary(&bytes, DecimalPrecision::Precision14, DecimalScale::Scale04).unwrap(), decimal);
+    let negative = Decimal::parse("-1234567890.1234").unwrap();
+    let bytes = negative.to_mysql_binary(DecimalPrecision::Precision14, DecimalScale::Scale04).unwrap();
+    assert_eq!(bytes, vec![0x7E, 0xF2, 0x04, 0xC7, 0x2D, 0xFB, 0x2D]);
+    assert_eq!(Decimal::from_mysql_binary(&bytes, DecimalPrecision::Precision14, DecimalScale::Scale04).unwrap().mantissa(), -12345678901234);
+
+    let zero = Decimal::parse("0").unwrap().to_mysql_binary(DecimalPrecision::Precision05, DecimalScale::Scale02).unwrap();
+    assert_eq!(zero, vec![0x80, 0x00, 0x00]);
+    let bytes = crate::DECIMAL_MAX.to_mysql_binary(DecimalPrecision::Precision38, DecimalScale::Scale00).unwrap();
+    assert_eq!(bytes.len(), 17);
+    assert_eq!(Decimal::from_mysql_binary(&bytes, DecimalPrecision::Precision38, DecimalScale::Scale00).unwrap().mantissa(), crate::DECIMAL_MAX.mantissa());
+    let small = Decimal::from_i128_with_scale(-1, 38).unwrap();
+    let bytes = small.to_mysql_binary(DecimalPrecision::Precision38, DecimalScale::Scale38).unwrap();
+    assert_eq!(Decimal::from_mysql_binary(&bytes, DecimalPrecision::Precision38, DecimalScale::Scale38).unwrap(), small);
+
+    assert!(decimal.to_mysql_binary(DecimalPrecision::Precision13, DecimalScale::Scale04).is_err());
+    assert!(decimal.to_mysql_binary(DecimalPrecision::Precision14, DecimalScale::Scale03).is_err());
+    assert!(Decimal::from_mysql_binary(&[0x81, 0x0D], DecimalPrecision::Precision14, DecimalScale::Scale04).is_err());
+    assert!(Decimal::from_mysql_binary(&[0x83, 0xE8], DecimalPrecision::Precision03, DecimalScale::Scale00).is_err());
+}
@@ -0,0 +1,142 @@
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign};
+
+const ORACLE_ZERO: u8 = 0x80;
+const ORACLE_TERMINATOR: u8 = 0x66; // Ends negative numbers with fewer than 20 mantissa bytes
+
+impl Decimal {
+    /// Oracle NUMBER internal format: an exponent byte for powers of 100, then base-100 digits plus one.
+    /// Negative numbers complement the exponent and store 101 minus each digit, followed by 0x66.
+    pub fn to_oracle_number(&self) -> Vec<u8> {
+        let (exponent, pairs) = match self.centesimal_digits() {
+            Some(value) => value,
+            None => return vec![ORACLE_ZERO],
+        };
+        let mut result = Vec::with_capacity(pairs.len() + 2);
+        if self.sign == DecimalSign::Negative {
+            result.push((62 - exponent) as u8);
+            result.extend(pairs.iter().map(|pair| 101 - pair));
+            if pairs.len() < 20 {
+                result.push(ORACLE_TERMINATOR);
+            }
+        } else {
+            result.push((193 + exponent) as u8);
+            result.extend(pairs.iter().map(|pair| pair + 1));
+        }
+        result
+    }
+    /// Reads the Oracle NUMBER internal format, infinities and values beyond 38 digits or scale 38 are errors.
+    pub fn from_oracle_number(bytes: &[u8]) -> Result<Decimal, DecimalError> {
+        let (negative, exponent, mantissa) = match bytes {
+            [ORACLE_ZERO] => return Decimal::from_coefficient(DecimalSign::Positive, DecimalScale::Scale00, 0),
+            [first, mantissa @ ..] if *first > ORACLE_ZERO && !mantissa.is_empty() => (false, *first as i32 - 193, mantissa),
+            [first, mantissa @ .., ORACLE_TERMINATOR] if *first < ORACLE_ZERO && !mantissa.is_empty() => (true, 62 - *first as i32, mantissa),
+            [first, mantissa @ ..] if *first < ORACLE_ZERO && mantissa.len() == 20 => (true, 62 - *first as i32, mantissa),
+            _ => return Err(DecimalError), // Empty, infinity or missing terminator
+        };
+        let mut pairs = Vec::with_capacity(mantissa.len());
+        for byte in mantissa {
+            let pair = if negative { 101i32 - *byte as i32 } else { *byte as i32 - 1 };
+            if !(0..100).contains(&pair) {
+                return Err(DecimalError); // Invalid mantissa byte
+            }
+            pairs.push(pair as u128);
+        }
+        Decimal::from_centesimal_digits(negative, exponent, &pairs)
+    }
+    // Base-100 digits aligned on the decimal point without leading or trailing zero digits,
+    // with the power of 100 of the first one. None for zero.
+    pub(crate) fn centesimal_digits(&self) -> Option<(i32, Vec<u8>)> {
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", self.coefficient(), width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        let integer = format!("{:0>width$}", integer, width = integer.len().div_ceil(2) * 2);
+        let fraction = format!("{:0<width$}", fraction, width = fraction.len().div_ceil(2) * 2);
+        let mut pairs: Vec<u8> = integer.as_bytes().chunks(2).chain(fraction.as_bytes().chunks(2))
+            .map(|pair| (pair[0] - b'0') * 10 + pair[1] - b'0')
+            .collect();
+        let leading = pairs.iter().take_while(|pair| **pair == 0).count();
+        if leading == pairs.len() {
+            return None;
+        }
+        pairs.drain(..leading);
+        while pairs.last() == Some(&0) {
+            pairs.pop();
+        }
+        Some(((integer.len() / 2) as i32 - 1 - leading as i32, pairs))
+    }
+    // Inverse of centesimal_digits, at the smallest scale that keeps the value exact
+    pub(crate) fn from_centesimal_digits(negative: bool, exponent: i32, pairs: &[u128]) -> Result<Decimal, DecimalError> {
+        if pairs.len() > 20 {
+            return Err(DecimalError); // More than 38 digits
+        }
+        // Power of 100 of the last digit, a negative one gives the scale
+        let last = exponent - (pairs.len() as i32 - 1);
+        let mut scale = if last < 0 { -2 * last } else { 0 };
+        let mut coefficient = 0u128;
+        for (index, pair) in pairs.iter().enumerate() {
+            let (factor, pair) = if index == pairs.len() - 1 && scale > 0 && pair.is_multiple_of(10) {
+                scale -= 1; // 0.5 is stored as the pair 50
+                (10, pair / 10)
+            } else {
+                (100, *pair)
+            };
+            coefficient = match coefficient.checked_mul(factor).and_then(|value| value.checked_add(pair)) {
+                Some(value) => value,
+                None => return Err(DecimalError), // Overflow
+            };
+        }
+        if last > 0 {
+            coefficient = match 100u128.checked_pow(last as u32).and_then(|factor| coefficient.checked_mul(factor)) {
+                Some(value) => value,
+                None => return Err(DecimalError), // Overflow
+            };
+        }
+        if scale > 38 {
+            return Err(DecimalError); // Below the smallest scale
+        }
+        let sign = if negative { DecimalSign::Negative } else { DecimalSign::Positive };
+        Decimal::from_coefficient(sign, DecimalScale::try_from_usize(scale as usize).unwrap(), coefficient)
+    }
+}
+
+#[test]
+fn decimal_oracle_number_works() {
+    let number = |text: &str| Decimal::parse(text).unwrap().to_oracle_number();
+    // SELECT DUMP(n) FROM DUAL
+    assert_eq!(number("0"), vec![0x80]);
+    assert_eq!(number("1"), vec![0xC1, 0x02]);
+    assert_eq!(number("-1"), vec![0x3E, 0x64, 0x66]);
+    assert_eq!(number("100"), vec![0xC2, 0x02]);
+    assert_eq!(number("123.45"), vec![0xC2, 0x02, 0x18, 0x2E]);
+    assert_eq!(number("-123.45"), vec![0x3D, 0x64, 0x4E, 0x38, 0x66]);
+    assert_eq!(number("0.5"), vec![0xC0, 0x33]);
+    assert_eq!(number("0.01"), vec![0xC0, 0x02]);
+    assert_eq!(number("1.10"), vec![0xC1, 0x02, 0x0B]);
+    assert_eq!(number("-1234567890123456789012345678901234567.8").len(), 21); // No terminator after 20 digits
+
+    let read = |bytes: &[u8]| Decimal::from_oracle_number(bytes).unwrap();
+    assert_eq!((read(&[0xC2, 0x02, 0x18, 0x2E]).mantissa(), read(&[0xC2, 0x02, 0x18, 0x2E]).scale()), (12345, 2));
+    assert_eq!(read(&[0x3D, 0x64, 0x4E, 0x38, 0x66]).mantissa(), -12345);
+    assert_eq!((read(&[0xC0, 0x33]).mantissa(), read(&[0xC0, 0x33]).scale()), (5, 1));
+    assert_eq!(read(&[0xC2, 0x02]).mantissa(), 100);
+    assert_eq!(read(&[0x80]).mantissa(), 0);
+    for mantissa in [1, -1, 99_999_999_999_999_999_999_999_999_999_999_999_999, -10_000_000_000_000_000_000_000_000_000_000_000_001] {
+        for scale in [0, 1, 17, 38] {
+            let decimal = Decimal::from_i128_with_scale(mantissa, scale).unwrap();
+            let decoded = Decimal::from_oracle_number(&decimal.to_oracle_number()).unwrap();
+            assert_eq!(decoded.rescale(DecimalScale::try_from_usize(scale as usize).unwrap(), crate::RoundingMode::Unnecessary).unwrap().mantissa(), mantissa);
+        }
+    }
+
+    assert!(Decimal::from_oracle_number(&[]).is_err());
+    assert!(Decimal::from_oracle_number(&[0xFF, 0x65]).is_err()); // Positive infinity
+    assert!(Decimal::from_oracle_number(&[0x00]).is_err()); // Negative infinity
+    assert!(Decimal::from_oracle_number(&[0x3E, 0x64]).is_err()); // No terminator
+    assert!(Decimal::from_oracle_number(&[0xC1, 0x66]).is_err()); // Digit 101
+    assert!(Decimal::from_oracle_number(&[0xD5, 0x02]).is_err()); // 10^40
+    assert!(Decimal::from_oracle_number(&[0xAB, 0x02]).is_err()); // 10^-40
+    // Crafted 20 digits whose last addition would wrap around u128
+    let crafted = [0xD3, 4, 41, 29, 24, 67, 93, 10, 39, 47, 35, 64, 38, 47, 8, 44, 18, 69, 22, 15, 100];
+    assert!(Decimal::from_oracle_number(&crafted).is_err());
+    assert!(Decimal::from_oracle_number(&[0xC1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2]).is_err()); // 21 digits
+}
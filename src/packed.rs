@@ -0,0 +1,109 @@
+use std::fmt;
+
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign, RoundingMode};
+
+/// Why a COBOL COMP-3 field cannot be encoded or decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PackedBcdError {
+    InvalidDigit { offset: usize, nibble: u8 }, // Nibble above 9 in the digit at `offset` (0 is the leftmost digit)
+    InvalidSign { nibble: u8 },                 // Sign nibble below 0xA
+    InvalidLength,                              // Empty field, zero digits or scale above the digits or 38
+    Overflow,                                   // Value does not fit the field, or above DECIMAL_MAX
+}
+
+impl fmt::Display for PackedBcdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PackedBcdError::InvalidDigit { offset, nibble } => write!(f, "Invalid digit nibble 0x{:X} at digit {}", nibble, offset),
+            PackedBcdError::InvalidSign { nibble } => write!(f, "Invalid sign nibble 0x{:X}", nibble),
+            PackedBcdError::InvalidLength => write!(f, "Invalid packed decimal length"),
+            PackedBcdError::Overflow => write!(f, "Packed decimal overflow"),
+        }
+    }
+}
+
+impl From<PackedBcdError> for DecimalError {
+    fn from(_: PackedBcdError) -> DecimalError {
+        DecimalError
+    }
+}
+
+impl Decimal {
+    /// COBOL `PIC S9(digits - scale)V9(scale) COMP-3`: two digits per byte, the last nibble is the sign
+    /// (0xC positive, 0xD negative). The value must fit the field exactly.
+    pub fn to_packed_bcd(&self, digits: u8, scale: u8) -> Result<Vec<u8>, PackedBcdError> {
+        if digits == 0 || scale > digits || scale > 38 {
+            return Err(PackedBcdError::InvalidLength);
+        }
+        let value = match self.rescale(DecimalScale::try_from_usize(scale as usize).unwrap(), RoundingMode::Unnecessary) {
+            Ok(value) => value,
+            Err(_) => return Err(PackedBcdError::Overflow), // More fractional digits than the field
+        };
+        let text = value.coefficient().to_string();
+        if text.len() > digits as usize {
+            return Err(PackedBcdError::Overflow);
+        }
+        // Odd number of nibbles before the sign, padded with a leading zero for an even digit count
+        let length = digits as usize / 2 + 1;
+        let mut nibbles = vec![0u8; length * 2 - 1 - text.len()];
+        nibbles.extend(text.bytes().map(|digit| digit - b'0'));
+        nibbles.push(if value.sign == DecimalSign::Negative && value.coefficient() != 0 { 0xD } else { 0xC });
+        Ok(nibbles.chunks(2).map(|pair| pair[0] << 4 | pair[1]).collect())
+    }
+    /// Reads a COMP-3 field with an implied `scale`. Sign nibbles 0xD and 0xB are negative,
+    /// 0xC, 0xF (unsigned), 0xA and 0xE are positive.
+    pub fn from_packed_bcd(bytes: &[u8], scale: u8) -> Result<Decimal, PackedBcdError> {
+        if bytes.is_empty() || scale > 38 || scale as usize > bytes.len() * 2 - 1 {
+            return Err(PackedBcdError::InvalidLength);
+        }
+        let mut coefficient = 0u128;
+        for offset in 0..bytes.len() * 2 - 1 {
+            let nibble = if offset % 2 == 0 { bytes[offset / 2] >> 4 } else { bytes[offset / 2] & 0x0F };
+            if nibble > 9 {
+                return Err(PackedBcdError::InvalidDigit { offset, nibble });
+            }
+            coefficient = match coefficient.checked_mul(10).and_then(|value| value.checked_add(nibble as u128)) {
+                Some(value) => value,
+                None => return Err(PackedBcdError::Overflow),
+            };
+        }
+        let sign = match bytes[bytes.len() - 1] & 0x0F {
+            0xB | 0xD => DecimalSign::Negative,
+            0xA | 0xC | 0xE | 0xF => DecimalSign::Positive,
+            nibble => return Err(PackedBcdError::InvalidSign { nibble }),
+        };
+        match Decimal::from_coefficient(sign, DecimalScale::try_from_usize(scale as usize).unwrap(), coefficient) {
+            Ok(value) => Ok(value),
+            Err(_) => Err(PackedBcdError::Overflow),
+        }
+    }
+}
+
+#[test]
+fn decimal_packed_bcd_works() {
+    // PIC S9(5)V99 COMP-3
+    let decimal = Decimal::parse("-12345.67").unwrap();
+    assert_eq!(decimal.to_packed_bcd(7, 2).unwrap(), vec![0x12, 0x34, 0x56, 0x7D]);
+    assert_eq!(Decimal::from_packed_bcd(&[0x12, 0x34, 0x56, 0x7D], 2).unwrap(), decimal);
+    // PIC S9(4)V99 COMP-3, even digit count gets a leading zero nibble
+    assert_eq!(Decimal::parse("12.5").unwrap().to_packed_bcd(6, 2).unwrap(), vec![0x00, 0x01, 0x25, 0x0C]);
+    assert_eq!(Decimal::from_packed_bcd(&[0x00, 0x01, 0x25, 0x0F], 2).unwrap().mantissa(), 1250);
+    assert_eq!(Decimal::from_packed_bcd(&[0x0C], 0).unwrap().mantissa(), 0);
+    assert_eq!(Decimal::from_packed_bcd(&[0x99, 0x9B], 1).unwrap().mantissa(), -999);
+
+    assert_eq!(Decimal::from_packed_bcd(&[0x12, 0x3A, 0x5C], 0), Err(PackedBcdError::InvalidDigit { offset: 3, nibble: 0xA }));
+    assert_eq!(Decimal::from_packed_bcd(&[0x12, 0x35], 0), Err(PackedBcdError::InvalidSign { nibble: 5 }));
+    assert_eq!(Decimal::from_packed_bcd(&[], 0), Err(PackedBcdError::InvalidLength));
+    assert_eq!(Decimal::from_packed_bcd(&[0x99; 20], 0), Err(PackedBcdError::Overflow));
+    // 39 digits: u128::MAX + 4 would wrap to 3 without the overflow check, 10^38 fits u128 but not a Decimal
+    let packed = |digits: &str| -> Vec<u8> {
+        let nibbles: Vec<u8> = digits.bytes().map(|digit| digit - b'0').chain(std::iter::once(0xC)).collect();
+        nibbles.chunks(2).map(|pair| pair[0] << 4 | pair[1]).collect()
+    };
+    assert_eq!(Decimal::from_packed_bcd(&packed("340282366920938463463374607431768211459"), 0), Err(PackedBcdError::Overflow));
+    assert_eq!(Decimal::from_packed_bcd(&packed("100000000000000000000000000000000000000"), 0), Err(PackedBcdError::Overflow));
+    assert_eq!(Decimal::from_packed_bcd(&packed("099999999999999999999999999999999999999"), 0).unwrap().mantissa(), crate::DECIMAL_MAX.mantissa());
+    assert_eq!(decimal.to_packed_bcd(6, 2), Err(PackedBcdError::Overflow));
+    assert_eq!(decimal.to_packed_bcd(7, 1), Err(PackedBcdError::Overflow));
+    assert_eq!(decimal.to_packed_bcd(7, 8), Err(PackedBcdError::InvalidLength));
+}
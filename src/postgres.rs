@@ -0,0 +1,110 @@
+use std::convert::TryInto;
+
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign};
+
+const PG_NUMERIC_POSITIVE: u16 = 0x0000;
+const PG_NUMERIC_NEGATIVE: u16 = 0x4000;
+const PG_NUMERIC_NBASE: u128 = 10000;
+
+impl Decimal {
+    /// PostgreSQL `numeric_send` format: big-endian `ndigits`, `weight`, `sign` and `dscale`,
+    /// then `ndigits` base-10000 digits, the first one multiplied by 10000^weight.
+    pub fn to_pg_numeric_binary(&self) -> Vec<u8> {
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", self.coefficient(), width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        // Base-10000 digits aligned on the decimal point
+        let integer = format!("{:0>width$}", integer, width = integer.len().div_ceil(4) * 4);
+        let fraction = format!("{:0<width$}", fraction, width = fraction.len().div_ceil(4) * 4);
+        let mut groups: Vec<u16> = integer.as_bytes().chunks(4).chain(fraction.as_bytes().chunks(4))
+            .map(|chunk| std::str::from_utf8(chunk).unwrap().parse().unwrap())
+            .collect();
+        let mut weight = (integer.len() / 4) as i16 - 1;
+        let leading = groups.iter().take_while(|group| **group == 0).count();
+        groups.drain(..leading);
+        weight -= leading as i16;
+        while groups.last() == Some(&0) {
+            groups.pop();
+        }
+        if groups.is_empty() {
+            weight = 0;
+        }
+        let sign = if self.sign == DecimalSign::Negative && !groups.is_empty() { PG_NUMERIC_NEGATIVE } else { PG_NUMERIC_POSITIVE };
+        let mut result = Vec::with_capacity(8 + 2 * groups.len());
+        result.extend_from_slice(&(groups.len() as i16).to_be_bytes());
+        result.extend_from_slice(&weight.to_be_bytes());
+        result.extend_from_slice(&sign.to_be_bytes());
+        result.extend_from_slice(&(scale as u16).to_be_bytes());
+        for group in groups {
+            result.extend_from_slice(&group.to_be_bytes());
+        }
+        result
+    }
+    /// Reads the `numeric_recv` format. NaN, infinities, a display scale above 38 and values above DECIMAL_MAX are errors.
+    pub fn from_pg_numeric_binary(bytes: &[u8]) -> Result<Decimal, DecimalError> {
+        if bytes.len() < 8 {
+            return Err(DecimalError);
+        }
+        let word = |index: usize| u16::from_be_bytes(bytes[2 * index..2 * index + 2].try_into().unwrap());
+        let (ndigits, weight, sign, dscale) = (word(0) as i16, word(1) as i16, word(2), word(3));
+        if ndigits < 0 || bytes.len() != 8 + 2 * ndigits as usize || dscale > 38 {
+            return Err(DecimalError);
+        }
+        let sign = match sign {
+            PG_NUMERIC_POSITIVE => DecimalSign::Positive,
+            PG_NUMERIC_NEGATIVE => DecimalSign::Negative,
+            _ => return Err(DecimalError), // NaN, infinity or invalid sign
+        };
+        let mut coefficient = 0u128;
+        for index in 0..ndigits as usize {
+            let digit = word(4 + index) as u128;
+            if digit >= PG_NUMERIC_NBASE {
+                return Err(DecimalError); // Invalid base-10000 digit
+            }
+            // The digit is worth 10^exponent units of the last dscale digit
+            let exponent = 4 * (weight as i32 - index as i32) + dscale as i32;
+            let value = if exponent >= 0 {
+                10u128.checked_pow(exponent as u32).and_then(|factor| digit.checked_mul(factor))
+            } else {
+                let divisor = 10u128.pow(exponent.unsigned_abs().min(4));
+                if !digit.is_multiple_of(divisor) {
+                    return Err(DecimalError); // Digits beyond dscale
+                }
+                Some(digit / divisor)
+            };
+            coefficient = match value.and_then(|value| coefficient.checked_add(value)) {
+                Some(value) => value,
+                None => return Err(DecimalError), // Overflow
+            };
+        }
+        Decimal::from_coefficient(sign, DecimalScale::try_from_usize(dscale as usize).unwrap(), coefficient)
+    }
+}
+
+#[test]
+fn decimal_pg_numeric_binary_works() {
+    let binary = |text: &str| Decimal::parse(text).unwrap().to_pg_numeric_binary();
+    // SELECT numeric_send(1234.5678) = \x0002000000000004 04d2 162e
+    assert_eq!(binary("1234.5678"), vec![0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0xD2, 0x16, 0x2E]);
+    assert_eq!(binary("0.01"), vec![0x00, 0x01, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x02, 0x00, 0x64]);
+    assert_eq!(binary("-12345"), vec![0x00, 0x02, 0x00, 0x01, 0x40, 0x00, 0x00, 0x00, 0x00, 0x01, 0x09, 0x29]);
+    assert_eq!(binary("10000.00"), vec![0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01]);
+    assert_eq!(binary("0.000"), vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+    for scale in 0..=38 {
+        for mantissa in [-1234567, -1, 100, 99_999_999_999_999_999_999_999_999_999_999_999_999] {
+            let decimal = Decimal::from_i128_with_scale(mantissa, scale).unwrap();
+            let decoded = Decimal::from_pg_numeric_binary(&decimal.to_pg_numeric_binary()).unwrap();
+            assert_eq!((decoded.mantissa(), decoded.scale()), (mantissa, scale));
+        }
+    }
+    let zero = Decimal::from_pg_numeric_binary(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03]).unwrap();
+    assert_eq!(zero.mantissa(), 0);
+
+    assert!(Decimal::from_pg_numeric_binary(&[0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00]).is_err()); // NaN
+    assert!(Decimal::from_pg_numeric_binary(&[0x00, 0x00, 0x00, 0x00, 0xD0, 0x00, 0x00, 0x00]).is_err()); // Infinity
+    assert!(Decimal::from_pg_numeric_binary(&[0x00, 0x01, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]).is_err()); // 10^40
+    assert!(Decimal::from_pg_numeric_binary(&[0x00, 0x01, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x01, 0x00, 0x64]).is_err()); // 0.01 with dscale 1
+    assert!(Decimal::from_pg_numeric_binary(&[0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27, 0x10]).is_err()); // Digit 10000
+    assert!(Decimal::from_pg_numeric_binary(&[0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]).is_err()); // Truncated
+}
@@ -0,0 +1,113 @@
+use std::convert::TryFrom;
+
+use crate::{Currency, Decimal, DecimalError, DecimalLocale, DecimalScale, DecimalSign, Money, RoundingMode};
+
+const NANOS_PER_UNIT: u128 = 1_000_000_000;
+
+/// Message shape of the well-known `google.type.Decimal`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProtoDecimal {
+    pub value: String,
+}
+
+/// Message shape of the well-known `google.type.Money`: whole units plus nanos (10^-9 units) of the same sign.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProtoMoney {
+    pub currency_code: String,
+    pub units: i64,
+    pub nanos: i32,
+}
+
+impl Decimal {
+    /// `google.type.Decimal` with the value written without exponent, e.g. `"-1234.50"`.
+    pub fn to_proto_decimal(&self) -> ProtoDecimal {
+        ProtoDecimal { value: self.format_locale(&DecimalLocale::new('.', None)) }
+    }
+    /// Reads a `google.type.Decimal`, including the optional exponent such as `"1.5E-3"`. Any malformed
+    /// value, the empty string of an unset field included, is an error.
+    pub fn from_proto_decimal(decimal: &ProtoDecimal) -> Result<Decimal, DecimalError> {
+        Decimal::parse_scientific(&decimal.value)
+    }
+    /// `google.type.Money` in `currency_code`. Digits beyond nanos are rounded with `mode`,
+    /// `RoundingMode::Unnecessary` rejects them. Fails when the units do not fit in 64 bits.
+    pub fn to_proto_money(&self, currency_code: &str, mode: RoundingMode) -> Result<ProtoMoney, DecimalError> {
+        let coefficient = self.rescale(DecimalScale::Scale09, mode)?.coefficient();
+        let units = match i64::try_from(coefficient / NANOS_PER_UNIT) {
+            Ok(units) => units,
+            Err(_) => return Err(DecimalError), // Overflow
+        };
+        let nanos = (coefficient % NANOS_PER_UNIT) as i32;
+        let negative = self.sign == DecimalSign::Negative;
+        Ok(ProtoMoney {
+            currency_code: currency_code.to_string(),
+            units: if negative { -units } else { units },
+            nanos: if negative { -nanos } else { nanos },
+        })
+    }
+    /// Amount of a `google.type.Money` at the smallest scale that keeps it exact. Fails when nanos
+    /// are out of range or their sign differs from the units.
+    pub fn from_proto_money(money: &ProtoMoney) -> Result<Decimal, DecimalError> {
+        if money.nanos.unsigned_abs() as u128 >= NANOS_PER_UNIT {
+            return Err(DecimalError); // Nanos out of range
+        }
+        if (money.units > 0 && money.nanos < 0) || (money.units < 0 && money.nanos > 0) {
+            return Err(DecimalError); // Units and nanos of different signs
+        }
+        let sign = if money.units < 0 || money.nanos < 0 { DecimalSign::Negative } else { DecimalSign::Positive };
+        let mut coefficient = money.units.unsigned_abs() as u128 * NANOS_PER_UNIT + money.nanos.unsigned_abs() as u128;
+        let mut scale = 9;
+        while scale > 0 && coefficient.is_multiple_of(10) {
+            coefficient /= 10;
+            scale -= 1;
+        }
+        Decimal::from_coefficient(sign, DecimalScale::try_from_usize(scale).unwrap(), coefficient)
+    }
+}
+
+impl Money {
+    /// `google.type.Money` with the currency code, exact since minor units never exceed nanos.
+    pub fn to_proto_money(&self) -> Result<ProtoMoney, DecimalError> {
+        self.amount().to_proto_money(self.currency().code, RoundingMode::Unnecessary)
+    }
+    /// Reads a `google.type.Money` of a known currency, rounding the amount to its minor units with `mode`.
+    pub fn from_proto_money(money: &ProtoMoney, mode: RoundingMode) -> Result<Money, DecimalError> {
+        let currency = Currency::from_code(&money.currency_code)?;
+        Money::new(&Decimal::from_proto_money(money)?, currency, mode)
+    }
+}
+
+#[test]
+fn decimal_protobuf_works() {
+    let decimal = Decimal::parse("-1234.50").unwrap();
+    assert_eq!(decimal.to_proto_decimal(), ProtoDecimal { value: "-1234.50".to_string() });
+    assert_eq!(Decimal::from_proto_decimal(&decimal.to_proto_decimal()).unwrap(), decimal);
+    assert_eq!(Decimal::from_proto_decimal(&ProtoDecimal { value: "1.5E-3".to_string() }).unwrap().mantissa(), 15);
+    assert!(Decimal::from_proto_decimal(&ProtoDecimal::default()).is_err());
+    for value in ["1,5", "1x5e3", "1.2.3e4", "--1e3", "+-1", "1e-2147483648", "1e2000000000", "1e", "-", ".", " 1"] {
+        assert!(Decimal::from_proto_decimal(&ProtoDecimal { value: value.to_string() }).is_err(), "{}", value);
+    }
+    assert_eq!(Decimal::from_proto_decimal(&ProtoDecimal { value: "+.5".to_string() }).unwrap().mantissa(), 5);
+
+    // -1.75 is { units: -1, nanos: -750000000 }
+    let money = Decimal::parse("-1.75").unwrap().to_proto_money("USD", RoundingMode::Unnecessary).unwrap();
+    assert_eq!(money, ProtoMoney { currency_code: "USD".to_string(), units: -1, nanos: -750_000_000 });
+    let amount = Decimal::from_proto_money(&money).unwrap();
+    assert_eq!((amount.mantissa(), amount.scale()), (-175, 2));
+    let amount = Decimal::from_proto_money(&ProtoMoney { currency_code: "USD".to_string(), units: 0, nanos: -1 }).unwrap();
+    assert_eq!((amount.mantissa(), amount.scale()), (-1, 9));
+
+    let excess = Decimal::parse("0.0000000015").unwrap();
+    assert!(excess.to_proto_money("USD", RoundingMode::Unnecessary).is_err());
+    assert_eq!(excess.to_proto_money("USD", RoundingMode::HalfEven).unwrap().nanos, 2);
+    assert!(Decimal::from_i128_with_scale(10_000_000_000_000_000_000, 0).unwrap().to_proto_money("USD", RoundingMode::Unnecessary).is_err()); // Above i64::MAX
+    let invalid = |units: i64, nanos: i32| Decimal::from_proto_money(&ProtoMoney { currency_code: "USD".to_string(), units, nanos }).is_err();
+    assert!(invalid(1, -1));
+    assert!(invalid(-1, 1));
+    assert!(invalid(0, 1_000_000_000));
+    assert!(invalid(0, i32::MIN));
+
+    let money = Money::from_proto_money(&ProtoMoney { currency_code: "JPY".to_string(), units: 100, nanos: 500_000_000 }, RoundingMode::HalfEven).unwrap();
+    assert_eq!(money.amount().mantissa(), 100);
+    assert_eq!(money.to_proto_money().unwrap(), ProtoMoney { currency_code: "JPY".to_string(), units: 100, nanos: 0 });
+    assert!(Money::from_proto_money(&ProtoMoney { currency_code: "XYZ".to_string(), units: 1, nanos: 0 }, RoundingMode::HalfEven).is_err());
+}
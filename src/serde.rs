@@ -0,0 +1,231 @@
+//! serde support. `Decimal` itself serializes as a string, the modules below select another
+//! representation with `#[serde(with = "wasm_decimal::serde::number")]` and so on.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use ::serde::de::{self, Deserializer, MapAccess, Unexpected, Visitor};
+use ::serde::ser::Serializer;
+use ::serde::{Deserialize, Serialize};
+
+use crate::{Decimal, DecimalLocale, FloatConversion};
+
+// Struct and field name of numbers kept as text by serde_json with its `arbitrary_precision` feature
+const JSON_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+fn plain_string(decimal: &Decimal) -> String {
+    decimal.format_locale(&DecimalLocale::new('.', None))
+}
+
+// Accepts every representation: strings, integers, floats, serde_json arbitrary precision numbers and objects
+struct DecimalVisitor;
+
+impl<'de> Visitor<'de> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a decimal number, a decimal string or a {{mantissa, scale}} object")
+    }
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Decimal, E> {
+        Decimal::parse_scientific(value).map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+    }
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    }
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    }
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Decimal, E> {
+        Decimal::try_from(value).map_err(|_| E::custom("integer above 38 digits"))
+    }
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Decimal, E> {
+        Decimal::try_from(value).map_err(|_| E::custom("integer above 38 digits"))
+    }
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Decimal, E> {
+        Decimal::from_f64(value, FloatConversion::Shortest).map_err(|_| E::invalid_value(Unexpected::Float(value), &self))
+    }
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Decimal, A::Error> {
+        let mut mantissa: Option<String> = None;
+        let mut scale: Option<u8> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                JSON_NUMBER_TOKEN => return self.visit_str(&map.next_value::<String>()?),
+                "mantissa" => mantissa = Some(map.next_value()?),
+                "scale" => scale = Some(map.next_value()?),
+                _ => return Err(de::Error::unknown_field(&key, &["mantissa", "scale"])),
+            }
+        }
+        let mantissa = mantissa.ok_or_else(|| de::Error::missing_field("mantissa"))?;
+        let scale = scale.ok_or_else(|| de::Error::missing_field("scale"))?;
+        match mantissa.parse::<i128>() {
+            Ok(value) => Decimal::from_i128_with_scale(value, scale).map_err(|_| de::Error::custom("mantissa above 38 digits or scale above 38")),
+            Err(_) => Err(de::Error::invalid_value(Unexpected::Str(&mantissa), &"an integer string")),
+        }
+    }
+}
+
+impl Serialize for Decimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        string::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        string::deserialize(deserializer)
+    }
+}
+
+// `#[serde(with = "...::option")]` for `Option<Decimal>` in the representation of the parent module,
+// attributes given to the macro go on the serializing half
+macro_rules! option_module {
+    ($(#[$serialize:meta])*) => {
+        /// `Option<Decimal>` in the same representation, `None` as null.
+        pub mod option {
+            use ::serde::{Deserialize, Deserializer};
+
+            use crate::Decimal;
+
+            struct Wrapper(Decimal);
+
+            $(#[$serialize])*
+            impl ::serde::Serialize for Wrapper {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::serialize(&self.0, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for Wrapper {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Wrapper, D::Error> {
+                    super::deserialize(deserializer).map(Wrapper)
+                }
+            }
+
+            $(#[$serialize])*
+            pub fn serialize<S: ::serde::Serializer>(value: &Option<Decimal>, serializer: S) -> Result<S::Ok, S::Error> {
+                match value {
+                    Some(value) => serializer.serialize_some(&Wrapper(*value)),
+                    None => serializer.serialize_none(),
+                }
+            }
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Decimal>, D::Error> {
+                Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|value| value.0))
+            }
+        }
+    };
+}
+
+/// Lossless string such as `"-1234.50"`, the default representation.
+pub mod string {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::Decimal;
+
+    pub fn serialize<S: Serializer>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::plain_string(value))
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        deserializer.deserialize_str(super::DecimalVisitor)
+    }
+
+    option_module!();
+}
+
+/// JSON number such as `-1234.50`, written as text without going through `f64`. `serialize` only exists with the
+/// `serde-arbitrary-precision` feature, which turns on serde_json's `arbitrary_precision`; without it serde_json
+/// would write a private token as an object key. Other formats get a one-field struct.
+/// Deserialization accepts any number or string and is always available.
+pub mod number {
+    #[cfg(feature = "serde-arbitrary-precision")]
+    use ::serde::ser::{SerializeStruct, Serializer};
+    use ::serde::Deserializer;
+
+    use crate::Decimal;
+
+    #[cfg(feature = "serde-arbitrary-precision")]
+    pub fn serialize<S: Serializer>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut number = serializer.serialize_struct(super::JSON_NUMBER_TOKEN, 1)?;
+        number.serialize_field(super::JSON_NUMBER_TOKEN, &super::plain_string(value))?;
+        number.end()
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        deserializer.deserialize_any(super::DecimalVisitor)
+    }
+
+    option_module!(#[cfg(feature = "serde-arbitrary-precision")]);
+}
+
+/// Object `{"mantissa": "-123450", "scale": 2}`, the mantissa is a string because JSON numbers stop at 53 bits.
+pub mod object {
+    use ::serde::ser::SerializeStruct;
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::Decimal;
+
+    pub fn serialize<S: Serializer>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut object = serializer.serialize_struct("Decimal", 2)?;
+        object.serialize_field("mantissa", &value.mantissa().to_string())?;
+        object.serialize_field("scale", &value.scale())?;
+        object.end()
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        deserializer.deserialize_struct("Decimal", &["mantissa", "scale"], super::DecimalVisitor)
+    }
+
+    option_module!();
+}
+
+#[test]
+fn decimal_serde_works() {
+    use serde_json::value::Serializer as ValueSerializer;
+    use serde_json::{json, Deserializer as JsonDeserializer};
+
+    let decimal = Decimal::parse("-1234.50").unwrap();
+    assert_eq!(serde_json::to_string(&decimal).unwrap(), "\"-1234.50\"");
+    assert_eq!(serde_json::from_str::<Decimal>("\"-1234.50\"").unwrap(), decimal);
+    assert!(serde_json::from_str::<Decimal>("\"12a\"").is_err());
+    // Untrusted text is rejected without panicking
+    for text in ["1x5e3", "1.2.3e4", "--1e3", "+-1", "1e-2147483648", "1e2000000000", "1e", "1e+", "e5", "-", ".", "", "1 ", "１"] {
+        assert!(serde_json::from_str::<Decimal>(&format!("\"{}\"", text)).is_err(), "{}", text);
+    }
+    let parse = |text: &str| serde_json::from_str::<Decimal>(&format!("\"{}\"", text)).unwrap();
+    assert_eq!((parse("1.5E+2").mantissa(), parse("1.5E+2").scale()), (150, 0));
+    assert_eq!(parse("+1").mantissa(), 1);
+    assert_eq!(parse(".5").mantissa(), 5);
+    assert_eq!((parse("1.50").mantissa(), parse("1.50").scale()), (150, 2));
+    assert_eq!((parse("100e-40").mantissa(), parse("100e-40").scale()), (1, 38));
+    assert_eq!(parse("0e-76").mantissa(), 0);
+    // Inexact input is rejected the same way with or without exponent
+    for text in ["0.0000000000000000000000000000000000000001", "1e-40", "1e-76", "0.999999999999999999999999999999999999999e0", "1e38"] {
+        assert!(serde_json::from_str::<Decimal>(&format!("\"{}\"", text)).is_err(), "{}", text);
+    }
+    assert!(serde_json::from_str::<Decimal>("\"1e77\"").is_err());
+
+    assert_eq!(object::serialize(&decimal, ValueSerializer).unwrap(), json!({"mantissa": "-123450", "scale": 2}));
+    #[cfg(feature = "serde-arbitrary-precision")]
+    {
+        let max = crate::DECIMAL_MAX;
+        assert_eq!(number::serialize(&decimal, ValueSerializer).unwrap().to_string(), "-1234.50");
+        assert_eq!(number::serialize(&max, ValueSerializer).unwrap().to_string(), "99999999999999999999999999999999999999");
+        assert_eq!(number::option::serialize(&Some(decimal), ValueSerializer).unwrap().to_string(), "-1234.50");
+    }
+
+    #[cfg(feature = "serde-arbitrary-precision")]
+    {
+        assert_eq!(number::deserialize(&mut JsonDeserializer::from_str("-1234.50")).unwrap(), decimal);
+        assert_eq!(number::deserialize(&mut JsonDeserializer::from_str("99999999999999999999999999999999999999")).unwrap().mantissa(), crate::DECIMAL_MAX.mantissa());
+    }
+    // Without arbitrary_precision serde_json hands over an f64, read as its shortest digits
+    #[cfg(not(feature = "serde-arbitrary-precision"))]
+    assert_eq!(number::deserialize(&mut JsonDeserializer::from_str("-1234.50")).unwrap().mantissa(), -12345);
+    assert_eq!(number::deserialize(&mut JsonDeserializer::from_str("1.5e-3")).unwrap().mantissa(), 15);
+    assert_eq!(number::deserialize(&mut JsonDeserializer::from_str("\"0.1\"")).unwrap().mantissa(), 1);
+    assert_eq!(object::deserialize(&mut JsonDeserializer::from_str("{\"scale\": 2, \"mantissa\": \"-123450\"}")).unwrap(), decimal);
+    assert!(object::deserialize(&mut JsonDeserializer::from_str("{\"mantissa\": \"1\"}")).is_err());
+    assert!(object::deserialize(&mut JsonDeserializer::from_str("{\"mantissa\": \"1\", \"scale\": 39}")).is_err());
+    assert!(string::deserialize(&mut JsonDeserializer::from_str("1.5")).is_err());
+
+    assert_eq!(string::option::serialize(&None, ValueSerializer).unwrap(), json!(null));
+    assert_eq!(object::option::deserialize(&mut JsonDeserializer::from_str("null")).unwrap(), None);
+    assert_eq!(string::option::deserialize(&mut JsonDeserializer::from_str("\"-1234.50\"")).unwrap(), Some(decimal));
+}
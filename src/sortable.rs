@@ -0,0 +1,103 @@
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign};
+
+const SORTABLE_ZERO: u8 = 0x80;
+const SORTABLE_TERMINATOR: u8 = 102; // Above every negative digit byte, so -1.5 sorts after -1.50001
+const SORTABLE_EXPONENT_BIAS: i32 = 19; // Power of 100 of the first digit goes from -19 (10^-38) to 18
+
+impl Decimal {
+    /// Order-preserving key: byte strings compare with `memcmp` as their values do, whatever the scale.
+    /// A header byte for the sign and power of 100, then base-100 digits; negative numbers complement both
+    /// and end with a terminator. Equal values such as 1.5 and 1.50 give the same bytes.
+    pub fn to_sortable_bytes(&self) -> Vec<u8> {
+        let (exponent, pairs) = match self.centesimal_digits() {
+            Some(value) => value,
+            None => return vec![SORTABLE_ZERO],
+        };
+        let mut result = Vec::with_capacity(pairs.len() + 2);
+        if self.sign == DecimalSign::Negative {
+            result.push((SORTABLE_ZERO as i32 - 1 - (exponent + SORTABLE_EXPONENT_BIAS)) as u8);
+            result.extend(pairs.iter().map(|pair| 101 - pair));
+            result.push(SORTABLE_TERMINATOR);
+        } else {
+            result.push((SORTABLE_ZERO as i32 + 1 + exponent + SORTABLE_EXPONENT_BIAS) as u8);
+            result.extend(pairs.iter().map(|pair| pair + 1));
+        }
+        result
+    }
+    /// Reads a key written by `to_sortable_bytes`, at the smallest scale that keeps the value exact.
+    pub fn from_sortable_bytes(bytes: &[u8]) -> Result<Decimal, DecimalError> {
+        let (negative, exponent, digits) = match bytes {
+            [SORTABLE_ZERO] => return Decimal::from_coefficient(DecimalSign::Positive, DecimalScale::Scale00, 0),
+            [first, digits @ ..] if *first > SORTABLE_ZERO && !digits.is_empty() => {
+                (false, *first as i32 - SORTABLE_ZERO as i32 - 1 - SORTABLE_EXPONENT_BIAS, digits)
+            },
+            [first, digits @ .., SORTABLE_TERMINATOR] if *first < SORTABLE_ZERO && !digits.is_empty() => {
+                (true, SORTABLE_ZERO as i32 - 1 - *first as i32 - SORTABLE_EXPONENT_BIAS, digits)
+            },
+            _ => return Err(DecimalError), // Empty, no digits or missing terminator
+        };
+        let mut pairs = Vec::with_capacity(digits.len());
+        for byte in digits {
+            let pair = if negative { 101i32 - *byte as i32 } else { *byte as i32 - 1 };
+            if !(0..100).contains(&pair) {
+                return Err(DecimalError); // Invalid digit byte
+            }
+            pairs.push(pair as u128);
+        }
+        if pairs.first() == Some(&0) || pairs.last() == Some(&0) {
+            return Err(DecimalError); // Not normalized, the key would sort out of order
+        }
+        Decimal::from_centesimal_digits(negative, exponent, &pairs)
+    }
+}
+
+#[test]
+fn decimal_sortable_bytes_works() {
+    let key = |text: &str| Decimal::parse(text).unwrap().to_sortable_bytes();
+    assert_eq!(key("0"), vec![0x80]);
+    assert_eq!(key("1.5"), vec![0x94, 0x02, 0x33]);
+    assert_eq!(key("1.50"), key("1.5"));
+    assert_eq!(key("-1.5"), vec![0x6C, 0x64, 0x33, 0x66]);
+
+    let ordered = [
+        Decimal::from_i128_with_scale(-99_999_999_999_999_999_999_999_999_999_999_999_999, 0).unwrap(),
+        Decimal::parse("-100").unwrap(),
+        Decimal::parse("-2").unwrap(),
+        Decimal::parse("-1.50001").unwrap(),
+        Decimal::parse("-1.5").unwrap(),
+        Decimal::parse("-1").unwrap(),
+        Decimal::parse("-0.01").unwrap(),
+        Decimal::from_i128_with_scale(-1, 38).unwrap(),
+        Decimal::parse("0").unwrap(),
+        Decimal::from_i128_with_scale(1, 38).unwrap(),
+        Decimal::parse("0.01").unwrap(),
+        Decimal::parse("0.1").unwrap(),
+        Decimal::parse("1").unwrap(),
+        Decimal::parse("1.5").unwrap(),
+        Decimal::parse("1.50001").unwrap(),
+        Decimal::parse("2").unwrap(),
+        Decimal::parse("100").unwrap(),
+        Decimal::from_i128_with_scale(99_999_999_999_999_999_999_999_999_999_999_999_999, 0).unwrap(),
+    ];
+    for pair in ordered.windows(2) {
+        assert!(pair[0].to_sortable_bytes() < pair[1].to_sortable_bytes(), "{:?}", pair);
+    }
+    for decimal in ordered.iter() {
+        let decoded = Decimal::from_sortable_bytes(&decimal.to_sortable_bytes()).unwrap();
+        assert_eq!(decoded.mantissa() * 10i128.pow((decimal.scale() - decoded.scale()) as u32), decimal.mantissa());
+    }
+    let decoded = Decimal::from_sortable_bytes(&key("-1.50")).unwrap();
+    assert_eq!((decoded.mantissa(), decoded.scale()), (-15, 1));
+
+    assert!(Decimal::from_sortable_bytes(&[]).is_err());
+    assert!(Decimal::from_sortable_bytes(&[0x94]).is_err()); // No digits
+    assert!(Decimal::from_sortable_bytes(&[0x6C, 0x64]).is_err()); // No terminator
+    assert!(Decimal::from_sortable_bytes(&[0x94, 0x66]).is_err()); // Digit 101
+    assert!(Decimal::from_sortable_bytes(&[0x94, 0x02, 0x01]).is_err()); // Trailing zero digit
+    // Crafted key from storage whose last digit would wrap around u128
+    let crafted = [0x80 + 1 + 18 + 19, 4, 41, 29, 24, 67, 93, 10, 39, 47, 35, 64, 38, 47, 8, 44, 18, 69, 22, 15, 100];
+    assert!(Decimal::from_sortable_bytes(&crafted).is_err());
+    let mut long = vec![0x80 + 1 + 18 + 19];
+    long.extend_from_slice(&[2; 21]);
+    assert!(Decimal::from_sortable_bytes(&long).is_err()); // 21 digits
+}
@@ -0,0 +1,160 @@
+use std::convert::TryInto;
+
+use crate::{Decimal, DecimalError, DecimalPrecision, DecimalScale, DecimalSign, RoundingMode};
+
+impl Decimal {
+    /// SQL Server `CAST(... AS varbinary)` layout: precision, scale, reserved byte, sign (1 positive),
+    /// then the little-endian 32-bit words of the mantissa, trimmed to the last nonzero word.
+    pub fn to_sqlserver_bytes(&self) -> Vec<u8> {
+        let mut result = vec![self.precision as u8, self.scale as u8, 0, self.sqlserver_sign()];
+        let words = [self.part_0, self.part_1, self.part_2, self.part_3];
+        let length = words.iter().rposition(|word| *word != 0).map_or(1, |index| index + 1);
+        for word in &words[..length] {
+            result.extend_from_slice(&word.to_le_bytes());
+        }
+        result
+    }
+    pub fn from_sqlserver_bytes(bytes: &[u8]) -> Result<Decimal, DecimalError> {
+        if bytes.len() < 8 || bytes.len() > 20 || !bytes.len().is_multiple_of(4) {
+            return Err(DecimalError); // Header plus one to four words
+        }
+        let precision = match DecimalPrecision::try_from_usize(bytes[0] as usize) {
+            Ok(value) => value,
+            Err(_) => return Err(DecimalError),
+        };
+        if bytes[1] > bytes[0] {
+            return Err(DecimalError); // Scale above precision
+        }
+        let scale = DecimalScale::try_from_usize(bytes[1] as usize).unwrap();
+        let sign = Decimal::sqlserver_sign_from(bytes[3])?;
+        let mut words = [0u32; 4];
+        for (word, chunk) in words.iter_mut().zip(bytes[4..].chunks(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Decimal::from_parts((sign, precision, scale, words[0], words[1], words[2], words[3]))
+    }
+    /// TDS DECIMALN/NUMERICN value of a `decimal(precision, scale)` column: length byte (5, 9, 13 or 17),
+    /// sign byte and a mantissa of 4, 8, 12 or 16 bytes as the precision requires.
+    pub fn to_sqlserver_tds(&self, precision: DecimalPrecision, scale: DecimalScale) -> Result<Vec<u8>, DecimalError> {
+        let value = self.rescale(scale, RoundingMode::Unnecessary)?;
+        let digits = value.coefficient().checked_ilog10().map_or(1, |log| log as usize + 1);
+        if digits > precision as usize || scale as usize > precision as usize {
+            return Err(DecimalError); // Does not fit the column
+        }
+        let length = Decimal::sqlserver_tds_length(precision);
+        let mut result = vec![length, value.sqlserver_sign()];
+        result.extend_from_slice(&value.coefficient().to_le_bytes()[..length as usize - 1]);
+        Ok(result)
+    }
+    /// Reads a TDS DECIMALN/NUMERICN value, a zero length byte is `NULL`.
+    pub fn from_sqlserver_tds(bytes: &[u8], precision: DecimalPrecision, scale: DecimalScale) -> Result<Option<Decimal>, DecimalError> {
+        let length = match bytes.first() {
+            Some(0) if bytes.len() == 1 => return Ok(None),
+            Some(value) => *value,
+            None => return Err(DecimalError),
+        };
+        if length != Decimal::sqlserver_tds_length(precision) || bytes.len() != length as usize + 1 {
+            return Err(DecimalError); // Length does not match the column precision
+        }
+        let sign = Decimal::sqlserver_sign_from(bytes[1])?;
+        let mut mantissa = [0u8; 16];
+        mantissa[..length as usize - 1].copy_from_slice(&bytes[2..]);
+        let coefficient = u128::from_le_bytes(mantissa);
+        if coefficient.checked_ilog10().map_or(1, |log| log as usize + 1) > precision as usize {
+            return Err(DecimalError); // More digits than the column precision
+        }
+        Decimal::from_coefficient(sign, scale, coefficient).map(Some)
+    }
+    /// SQL Server MONEY: a 64-bit integer of ten-thousandths, high 32-bit word first.
+    pub fn to_sqlserver_money(&self, mode: RoundingMode) -> Result<[u8; 8], DecimalError> {
+        let value: i64 = match self.rescale(DecimalScale::Scale04, mode)?.mantissa().try_into() {
+            Ok(value) => value,
+            Err(_) => return Err(DecimalError), // Outside the MONEY range
+        };
+        let mut result = [0u8; 8];
+        result[..4].copy_from_slice(&((value >> 32) as u32).to_le_bytes());
+        result[4..].copy_from_slice(&(value as u32).to_le_bytes());
+        Ok(result)
+    }
+    pub fn from_sqlserver_money(bytes: [u8; 8]) -> Decimal {
+        let high = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let low = u32::from_le_bytes(bytes[4..].try_into().unwrap());
+        let value = ((high as u64) << 32 | low as u64) as i64;
+        Decimal::from_i128_with_scale(value as i128, 4).unwrap()
+    }
+    /// SQL Server SMALLMONEY: a 32-bit integer of ten-thousandths.
+    pub fn to_sqlserver_smallmoney(&self, mode: RoundingMode) -> Result<[u8; 4], DecimalError> {
+        match self.rescale(DecimalScale::Scale04, mode)?.mantissa().try_into() {
+            Ok(value) => Ok(i32::to_le_bytes(value)),
+            Err(_) => Err(DecimalError), // Outside the SMALLMONEY range
+        }
+    }
+    pub fn from_sqlserver_smallmoney(bytes: [u8; 4]) -> Decimal {
+        Decimal::from_i128_with_scale(i32::from_le_bytes(bytes) as i128, 4).unwrap()
+    }
+    fn sqlserver_sign(&self) -> u8 {
+        if self.sign == DecimalSign::Negative && self.coefficient() != 0 { 0 } else { 1 }
+    }
+    fn sqlserver_sign_from(byte: u8) -> Result<DecimalSign, DecimalError> {
+        match byte {
+            0 => Ok(DecimalSign::Negative),
+            1 => Ok(DecimalSign::Positive),
+            _ => Err(DecimalError), // Invalid sign byte
+        }
+    }
+    fn sqlserver_tds_length(precision: DecimalPrecision) -> u8 {
+        match precision as u8 {
+            1..=9 => 5,
+            10..=19 => 9,
+            20..=28 => 13,
+            _ => 17,
+        }
+    }
+}
+
+#[test]
+fn decimal_sqlserver_bytes_works() {
+    // Samples from Decimal_Samples.txt
+    let one = Decimal::from_sqlserver_bytes(&[0x26, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00]).unwrap();
+    assert_eq!(one.mantissa(), 1);
+    assert_eq!(one.to_sqlserver_bytes(), vec![0x26, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00]);
+    let tenth = Decimal::from_sqlserver_bytes(&[0x26, 0x01, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00]).unwrap();
+    assert_eq!((tenth.mantissa(), tenth.scale()), (1, 1));
+    let bytes = [0x26, 0x00, 0x00, 0x01, 0x00, 0xE4, 0x0B, 0x54, 0x02, 0x00, 0x00, 0x00];
+    assert_eq!(Decimal::from_sqlserver_bytes(&bytes).unwrap().mantissa(), 10_000_000_000);
+    let bytes = [0x26, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0x3F, 0x22, 0x8A, 0x09, 0x7A, 0xC4, 0x86, 0x5A, 0xA8, 0x4C, 0x3B, 0x4B];
+    assert_eq!(Decimal::from_sqlserver_bytes(&bytes).unwrap().to_sqlserver_bytes(), bytes.to_vec());
+
+    let decimal = Decimal::parse("-1234.56").unwrap();
+    assert_eq!(Decimal::from_sqlserver_bytes(&decimal.to_sqlserver_bytes()).unwrap(), decimal);
+    assert!(Decimal::from_sqlserver_bytes(&[0x26, 0x00, 0x00, 0x02, 0x01, 0x00, 0x00, 0x00]).is_err());
+    assert!(Decimal::from_sqlserver_bytes(&[0x02, 0x00, 0x00, 0x01, 0xE8, 0x03, 0x00, 0x00]).is_err());
+    assert!(Decimal::from_sqlserver_bytes(&[0x26, 0x00, 0x00, 0x01, 0x01, 0x00]).is_err());
+}
+
+#[test]
+fn decimal_sqlserver_tds_works() {
+    let decimal = Decimal::parse("-1234.56").unwrap();
+    let bytes = decimal.to_sqlserver_tds(DecimalPrecision::Precision10, DecimalScale::Scale02).unwrap();
+    assert_eq!(bytes, vec![0x09, 0x00, 0x40, 0xE2, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    assert_eq!(Decimal::from_sqlserver_tds(&bytes, DecimalPrecision::Precision10, DecimalScale::Scale02).unwrap(), Some(decimal));
+    let bytes = decimal.to_sqlserver_tds(DecimalPrecision::Precision09, DecimalScale::Scale04).unwrap();
+    assert_eq!(bytes, vec![0x05, 0x00, 0x00, 0x61, 0xBC, 0x00]);
+    assert_eq!(decimal.to_sqlserver_tds(DecimalPrecision::Precision28, DecimalScale::Scale02).unwrap().len(), 14);
+    assert_eq!(decimal.to_sqlserver_tds(DecimalPrecision::Precision38, DecimalScale::Scale02).unwrap().len(), 18);
+    assert_eq!(Decimal::from_sqlserver_tds(&[0x00], DecimalPrecision::Precision10, DecimalScale::Scale02).unwrap(), None);
+    assert!(decimal.to_sqlserver_tds(DecimalPrecision::Precision05, DecimalScale::Scale02).is_err());
+    assert!(decimal.to_sqlserver_tds(DecimalPrecision::Precision10, DecimalScale::Scale01).is_err());
+    assert!(Decimal::from_sqlserver_tds(&[0x05, 0x01, 0x00, 0xCA, 0x9A, 0x3B], DecimalPrecision::Precision09, DecimalScale::Scale00).is_err());
+
+    let price = Decimal::parse("-1.00005").unwrap();
+    let money = price.to_sqlserver_money(RoundingMode::HalfEven).unwrap();
+    assert_eq!(money, [0xFF, 0xFF, 0xFF, 0xFF, 0xF0, 0xD8, 0xFF, 0xFF]);
+    assert_eq!(Decimal::from_sqlserver_money(money).mantissa(), -10000);
+    assert!(price.to_sqlserver_money(RoundingMode::Unnecessary).is_err());
+    assert_eq!(Decimal::from_sqlserver_money([0x00, 0x00, 0x00, 0x00, 0x10, 0x27, 0x00, 0x00]).mantissa(), 10000);
+    let smallmoney = Decimal::parse("214748.3647").unwrap().to_sqlserver_smallmoney(RoundingMode::Unnecessary).unwrap();
+    assert_eq!(smallmoney, [0xFF, 0xFF, 0xFF, 0x7F]);
+    assert_eq!(Decimal::from_sqlserver_smallmoney(smallmoney).mantissa(), 2_147_483_647);
+    assert!(Decimal::parse("214748.3648").unwrap().to_sqlserver_smallmoney(RoundingMode::Unnecessary).is_err());
+}
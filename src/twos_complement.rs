@@ -0,0 +1,70 @@
+use crate::{Decimal, DecimalError};
+
+impl Decimal {
+    /// Unscaled value as big-endian two's complement in exactly `length` bytes, as Parquet
+    /// FIXED_LEN_BYTE_ARRAY decimals. Fails when the value needs more bytes.
+    pub fn to_be_twos_complement(&self, length: usize) -> Result<Vec<u8>, DecimalError> {
+        let minimal = self.to_be_twos_complement_minimal();
+        if length < minimal.len() {
+            return Err(DecimalError); // Does not fit
+        }
+        let extension = if self.mantissa() < 0 { 0xFF } else { 0x00 };
+        let mut result = vec![extension; length - minimal.len()];
+        result.extend_from_slice(&minimal);
+        Ok(result)
+    }
+    /// Shortest big-endian two's complement of the unscaled value, as Java `BigInteger.toByteArray()`
+    /// and the Avro `decimal` logical type on `bytes`.
+    pub fn to_be_twos_complement_minimal(&self) -> Vec<u8> {
+        let bytes = self.mantissa().to_be_bytes();
+        // A leading byte is redundant when it only repeats the sign bit of the next one
+        let redundant = bytes.windows(2)
+            .take_while(|pair| (pair[0] == 0x00 && pair[1] & 0x80 == 0) || (pair[0] == 0xFF && pair[1] & 0x80 != 0))
+            .count();
+        bytes[redundant..].to_vec()
+    }
+    /// Reads a big-endian two's complement unscaled value of any length with the given `scale`.
+    pub fn from_be_twos_complement(bytes: &[u8], scale: u8) -> Result<Decimal, DecimalError> {
+        if bytes.is_empty() {
+            return Err(DecimalError);
+        }
+        let extension = if bytes[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+        let split = bytes.len().saturating_sub(16);
+        let (prefix, value) = bytes.split_at(split);
+        if prefix.iter().any(|byte| *byte != extension) || (!prefix.is_empty() && (value[0] & 0x80 != 0) != (extension == 0xFF)) {
+            return Err(DecimalError); // Overflow
+        }
+        let mut buffer = [extension; 16];
+        buffer[16 - value.len()..].copy_from_slice(value);
+        Decimal::from_i128_with_scale(i128::from_be_bytes(buffer), scale)
+    }
+}
+
+#[test]
+fn decimal_twos_complement_works() {
+    let decimal = Decimal::parse("-1234.56").unwrap(); // Unscaled -123456 = 0xFE1DC0
+    assert_eq!(decimal.to_be_twos_complement_minimal(), vec![0xFE, 0x1D, 0xC0]);
+    assert_eq!(decimal.to_be_twos_complement(5).unwrap(), vec![0xFF, 0xFF, 0xFE, 0x1D, 0xC0]);
+    assert_eq!(decimal.to_be_twos_complement(20).unwrap().len(), 20);
+    assert!(decimal.to_be_twos_complement(2).is_err());
+    assert_eq!(Decimal::from_be_twos_complement(&[0xFF, 0xFF, 0xFE, 0x1D, 0xC0], 2).unwrap(), decimal);
+    assert_eq!(Decimal::from_be_twos_complement(&decimal.to_be_twos_complement(20).unwrap(), 2).unwrap().mantissa(), -123456);
+
+    // BigInteger.valueOf(128).toByteArray() == { 0x00, 0x80 }
+    assert_eq!(Decimal::from(128).to_be_twos_complement_minimal(), vec![0x00, 0x80]);
+    assert_eq!(Decimal::from(-128).to_be_twos_complement_minimal(), vec![0x80]);
+    assert_eq!(Decimal::from(0).to_be_twos_complement_minimal(), vec![0x00]);
+    assert_eq!(Decimal::from(-1).to_be_twos_complement_minimal(), vec![0xFF]);
+    assert_eq!(Decimal::from_be_twos_complement(&[0x80], 0).unwrap().mantissa(), -128);
+    let max = crate::DECIMAL_MAX.to_be_twos_complement_minimal();
+    assert_eq!(max.len(), 16);
+    assert_eq!(Decimal::from_be_twos_complement(&max, 0).unwrap().mantissa(), crate::DECIMAL_MAX.mantissa());
+
+    assert!(Decimal::from_be_twos_complement(&[], 0).is_err());
+    assert!(Decimal::from_be_twos_complement(&[0x7F; 16], 0).is_err()); // Above DECIMAL_MAX
+    assert!(Decimal::from_be_twos_complement(&[0x01; 17], 0).is_err());
+    assert!(Decimal::from_be_twos_complement(&[0x01], 39).is_err());
+    let mut padded = vec![0x00];
+    padded.extend_from_slice(&[0x80; 16]);
+    assert!(Decimal::from_be_twos_complement(&padded, 0).is_err()); // 2^127 and above
+}
@@ -0,0 +1,40 @@
+//! JavaScript bindings, compiled only with the `wasm` feature (`wasm-pack build -- --features wasm`).
+
+use wasm_bindgen::prelude::*;
+
+use crate::Decimal;
+
+#[wasm_bindgen]
+extern "C" {
+    fn alert(s: &str);
+}
+
+#[wasm_bindgen]
+pub fn greet() {
+    alert("Hello, wasm-decimal!");
+}
+
+#[wasm_bindgen]
+pub fn calculation(operand1: String, operand2: String, operation: i32) -> String {
+    let decimal_operand1 = Decimal::parse(&operand1).unwrap();
+    let decimal_operand2 = Decimal::parse(&operand2).unwrap();
+    match operation {
+        0 => {
+            let result = decimal_operand1.add(&decimal_operand2);
+            result.to_string()
+        },
+        1 => {
+            let result = decimal_operand1.sub(&decimal_operand2);
+            result.to_string()
+        },
+        2 => {
+            let result = decimal_operand1.mul(&decimal_operand2).unwrap();
+            result.to_string()
+        },
+        4 => {
+            let result = decimal_operand1.div(&decimal_operand2);
+            result.to_string()
+        },
+        _ => String::from("0"),
+    }
+}
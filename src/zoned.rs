@@ -0,0 +1,201 @@
+use std::fmt;
+
+use crate::{Decimal, DecimalError, DecimalScale, DecimalSign, RoundingMode};
+
+/// Character set of a zoned decimal (COBOL `USAGE DISPLAY`) field.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZonedCharset {
+    Ebcdic, // Digits 0xF0..0xF9, overpunch zones 0xC (positive) and 0xD (negative)
+    Ascii,  // Digits '0'..'9', overpunch characters {ABCDEFGHI (positive) and }JKLMNOPQR (negative)
+}
+
+/// Where a zoned decimal field keeps its sign.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZonedSign {
+    TrailingOverpunch, // PIC S9(n), the default in COBOL
+    LeadingOverpunch,  // PIC S9(n) SIGN IS LEADING
+    TrailingSeparate,  // PIC S9(n) SIGN IS TRAILING SEPARATE
+    LeadingSeparate,   // PIC S9(n) SIGN IS LEADING SEPARATE
+    Unsigned,          // PIC 9(n)
+}
+
+/// Why a zoned decimal field cannot be encoded or decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZonedDecimalError {
+    InvalidDigit { offset: usize, byte: u8 }, // Byte at `offset` is not a digit of the charset
+    InvalidSign { offset: usize, byte: u8 },  // Byte at `offset` is neither an overpunch nor a sign character
+    InvalidLength,                            // Zero digits or scale above the digits or 38
+    Overflow,                                 // Value does not fit the field, negative in an unsigned field, or above DECIMAL_MAX
+}
+
+impl fmt::Display for ZonedDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ZonedDecimalError::InvalidDigit { offset, byte } => write!(f, "Invalid digit 0x{:02X} at byte {}", byte, offset),
+            ZonedDecimalError::InvalidSign { offset, byte } => write!(f, "Invalid sign 0x{:02X} at byte {}", byte, offset),
+            ZonedDecimalError::InvalidLength => write!(f, "Invalid zoned decimal length"),
+            ZonedDecimalError::Overflow => write!(f, "Zoned decimal overflow"),
+        }
+    }
+}
+
+impl From<ZonedDecimalError> for DecimalError {
+    fn from(_: ZonedDecimalError) -> DecimalError {
+        DecimalError
+    }
+}
+
+impl ZonedCharset {
+    fn digit(self, digit: u8) -> u8 {
+        match self {
+            ZonedCharset::Ebcdic => 0xF0 | digit,
+            ZonedCharset::Ascii => b'0' + digit,
+        }
+    }
+    fn overpunch(self, digit: u8, negative: bool) -> u8 {
+        match (self, negative) {
+            (ZonedCharset::Ebcdic, false) => 0xC0 | digit,
+            (ZonedCharset::Ebcdic, true) => 0xD0 | digit,
+            (ZonedCharset::Ascii, false) => if digit == 0 { b'{' } else { b'A' + digit - 1 },
+            (ZonedCharset::Ascii, true) => if digit == 0 { b'}' } else { b'J' + digit - 1 },
+        }
+    }
+    fn separate(self, negative: bool) -> u8 {
+        match (self, negative) {
+            (ZonedCharset::Ebcdic, false) => 0x4E,
+            (ZonedCharset::Ebcdic, true) => 0x60,
+            (ZonedCharset::Ascii, false) => b'+',
+            (ZonedCharset::Ascii, true) => b'-',
+        }
+    }
+    fn read_digit(self, byte: u8) -> Option<u8> {
+        match self {
+            ZonedCharset::Ebcdic if (0xF0..=0xF9).contains(&byte) => Some(byte & 0x0F),
+            ZonedCharset::Ascii if byte.is_ascii_digit() => Some(byte - b'0'),
+            _ => None,
+        }
+    }
+    // Digit and sign of an overpunched byte, a plain digit is positive
+    fn read_overpunch(self, byte: u8) -> Option<(u8, bool)> {
+        if let Some(digit) = self.read_digit(byte) {
+            return Some((digit, false));
+        }
+        match (self, byte) {
+            (ZonedCharset::Ebcdic, _) if byte & 0x0F > 9 => None,
+            (ZonedCharset::Ebcdic, 0xC0..=0xCF) => Some((byte & 0x0F, false)),
+            (ZonedCharset::Ebcdic, 0xD0..=0xDF) => Some((byte & 0x0F, true)),
+            (ZonedCharset::Ascii, b'{') => Some((0, false)),
+            (ZonedCharset::Ascii, b'A'..=b'I') => Some((byte - b'A' + 1, false)),
+            (ZonedCharset::Ascii, b'}') => Some((0, true)),
+            (ZonedCharset::Ascii, b'J'..=b'R') => Some((byte - b'J' + 1, true)),
+            _ => None,
+        }
+    }
+}
+
+impl Decimal {
+    /// Zoned decimal field of `digits` digits with an implied `scale`, one byte per digit plus a separate sign if any.
+    /// The value must fit the field exactly.
+    pub fn to_zoned(&self, digits: u8, scale: u8, charset: ZonedCharset, sign: ZonedSign) -> Result<Vec<u8>, ZonedDecimalError> {
+        if digits == 0 || scale > digits || scale > 38 {
+            return Err(ZonedDecimalError::InvalidLength);
+        }
+        let value = match self.rescale(DecimalScale::try_from_usize(scale as usize).unwrap(), RoundingMode::Unnecessary) {
+            Ok(value) => value,
+            Err(_) => return Err(ZonedDecimalError::Overflow), // More fractional digits than the field
+        };
+        let negative = value.sign == DecimalSign::Negative && value.coefficient() != 0;
+        let text = format!("{:0>width$}", value.coefficient(), width = digits as usize);
+        if text.len() > digits as usize || (negative && sign == ZonedSign::Unsigned) {
+            return Err(ZonedDecimalError::Overflow);
+        }
+        let mut result: Vec<u8> = text.bytes().map(|digit| charset.digit(digit - b'0')).collect();
+        match sign {
+            ZonedSign::TrailingOverpunch => *result.last_mut().unwrap() = charset.overpunch(text.as_bytes()[text.len() - 1] - b'0', negative),
+            ZonedSign::LeadingOverpunch => result[0] = charset.overpunch(text.as_bytes()[0] - b'0', negative),
+            ZonedSign::TrailingSeparate => result.push(charset.separate(negative)),
+            ZonedSign::LeadingSeparate => result.insert(0, charset.separate(negative)),
+            ZonedSign::Unsigned => {},
+        }
+        Ok(result)
+    }
+    /// Reads a zoned decimal field with an implied `scale`. Overpunch positions also accept a plain digit (positive).
+    pub fn from_zoned(bytes: &[u8], scale: u8, charset: ZonedCharset, sign: ZonedSign) -> Result<Decimal, ZonedDecimalError> {
+        let separate = matches!(sign, ZonedSign::TrailingSeparate | ZonedSign::LeadingSeparate) as usize;
+        if bytes.len() <= separate || scale > 38 || scale as usize > bytes.len() - separate {
+            return Err(ZonedDecimalError::InvalidLength);
+        }
+        let sign_offset = match sign {
+            ZonedSign::TrailingOverpunch | ZonedSign::TrailingSeparate => bytes.len() - 1,
+            ZonedSign::LeadingOverpunch | ZonedSign::LeadingSeparate => 0,
+            ZonedSign::Unsigned => usize::MAX,
+        };
+        let mut negative = false;
+        let mut coefficient = 0u128;
+        for (offset, byte) in bytes.iter().copied().enumerate() {
+            let digit = if offset != sign_offset {
+                match charset.read_digit(byte) {
+                    Some(digit) => digit,
+                    None => return Err(ZonedDecimalError::InvalidDigit { offset, byte }),
+                }
+            } else if separate == 1 {
+                negative = match byte {
+                    _ if byte == charset.separate(false) => false,
+                    _ if byte == charset.separate(true) => true,
+                    _ => return Err(ZonedDecimalError::InvalidSign { offset, byte }),
+                };
+                continue;
+            } else {
+                match charset.read_overpunch(byte) {
+                    Some((digit, minus)) => {
+                        negative = minus;
+                        digit
+                    },
+                    None => return Err(ZonedDecimalError::InvalidSign { offset, byte }),
+                }
+            };
+            coefficient = match coefficient.checked_mul(10).and_then(|value| value.checked_add(digit as u128)) {
+                Some(value) => value,
+                None => return Err(ZonedDecimalError::Overflow),
+            };
+        }
+        let sign = if negative { DecimalSign::Negative } else { DecimalSign::Positive };
+        match Decimal::from_coefficient(sign, DecimalScale::try_from_usize(scale as usize).unwrap(), coefficient) {
+            Ok(value) => Ok(value),
+            Err(_) => Err(ZonedDecimalError::Overflow),
+        }
+    }
+}
+
+#[test]
+fn decimal_zoned_works() {
+    // PIC S9(5)V99
+    let decimal = Decimal::parse("-123.45").unwrap();
+    assert_eq!(decimal.to_zoned(7, 2, ZonedCharset::Ascii, ZonedSign::TrailingOverpunch).unwrap(), b"001234N".to_vec());
+    assert_eq!(decimal.to_zoned(7, 2, ZonedCharset::Ebcdic, ZonedSign::TrailingOverpunch).unwrap(), vec![0xF0, 0xF0, 0xF1, 0xF2, 0xF3, 0xF4, 0xD5]);
+    assert_eq!(decimal.to_zoned(7, 2, ZonedCharset::Ascii, ZonedSign::LeadingOverpunch).unwrap(), b"}012345".to_vec());
+    assert_eq!(decimal.to_zoned(7, 2, ZonedCharset::Ascii, ZonedSign::LeadingSeparate).unwrap(), b"-0012345".to_vec());
+    assert_eq!(decimal.to_zoned(7, 2, ZonedCharset::Ebcdic, ZonedSign::TrailingSeparate).unwrap(), vec![0xF0, 0xF0, 0xF1, 0xF2, 0xF3, 0xF4, 0xF5, 0x60]);
+    assert_eq!(Decimal::parse("100").unwrap().to_zoned(5, 2, ZonedCharset::Ascii, ZonedSign::TrailingOverpunch).unwrap(), b"1000{".to_vec());
+    for sign in [ZonedSign::TrailingOverpunch, ZonedSign::LeadingOverpunch, ZonedSign::TrailingSeparate, ZonedSign::LeadingSeparate] {
+        for charset in [ZonedCharset::Ebcdic, ZonedCharset::Ascii] {
+            let bytes = decimal.to_zoned(9, 3, charset, sign).unwrap();
+            assert_eq!(Decimal::from_zoned(&bytes, 3, charset, sign).unwrap().mantissa(), -123450);
+        }
+    }
+
+    assert_eq!(Decimal::from_zoned(b"1234567", 2, ZonedCharset::Ascii, ZonedSign::TrailingOverpunch).unwrap().mantissa(), 1234567);
+    assert_eq!(Decimal::from_zoned(&[0xF1, 0xF2, 0xC0], 0, ZonedCharset::Ebcdic, ZonedSign::TrailingOverpunch).unwrap().mantissa(), 120);
+    assert_eq!(Decimal::from_zoned(b"12R", 0, ZonedCharset::Ascii, ZonedSign::TrailingOverpunch).unwrap().mantissa(), -129);
+    assert_eq!(Decimal::from_zoned(b"1 3", 0, ZonedCharset::Ascii, ZonedSign::Unsigned), Err(ZonedDecimalError::InvalidDigit { offset: 1, byte: b' ' }));
+    assert_eq!(Decimal::from_zoned(b"12S", 0, ZonedCharset::Ascii, ZonedSign::TrailingOverpunch), Err(ZonedDecimalError::InvalidSign { offset: 2, byte: b'S' }));
+    assert_eq!(Decimal::from_zoned(b"*12", 0, ZonedCharset::Ascii, ZonedSign::LeadingSeparate), Err(ZonedDecimalError::InvalidSign { offset: 0, byte: b'*' }));
+    assert_eq!(Decimal::from_zoned(b"-", 0, ZonedCharset::Ascii, ZonedSign::LeadingSeparate), Err(ZonedDecimalError::InvalidLength));
+    // u128::MAX + 4 would wrap to 3 without the overflow check
+    assert_eq!(Decimal::from_zoned(b"340282366920938463463374607431768211459", 0, ZonedCharset::Ascii, ZonedSign::Unsigned), Err(ZonedDecimalError::Overflow));
+    assert_eq!(Decimal::from_zoned(b"100000000000000000000000000000000000000", 0, ZonedCharset::Ascii, ZonedSign::Unsigned), Err(ZonedDecimalError::Overflow));
+    assert_eq!(decimal.to_zoned(4, 2, ZonedCharset::Ascii, ZonedSign::TrailingOverpunch), Err(ZonedDecimalError::Overflow));
+    assert_eq!(decimal.to_zoned(7, 2, ZonedCharset::Ascii, ZonedSign::Unsigned), Err(ZonedDecimalError::Overflow));
+}